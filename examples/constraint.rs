@@ -33,7 +33,7 @@ pub fn show<C: HasDisplay<X, T = X>, X>(
 
 pub fn display_witness<X: Display>() -> impl HasDisplay<X, T = X> {}
 
-pub fn display_witness2<X>(
+pub fn display_witness2<X: Display>(
   witness: impl HasDisplay<X, T = X>
 ) -> impl HasDisplay<X, T = X>
 {
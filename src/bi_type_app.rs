@@ -1,6 +1,14 @@
 use std::marker::PhantomData;
 
-use crate::type_app::*;
+use crate::{
+  refl::{
+    has_refl,
+    refl_symmetric,
+    HasReflUnbounded,
+    Refl,
+  },
+  type_app::*,
+};
 
 // F: BiTypeCon :: Type -> Type -> Type
 pub trait BiTypeCon {}
@@ -27,6 +35,78 @@ pub trait BiTypeAppGenericCont<'a, F: 'a, X: 'a, Y: 'a, R: 'a>
     F: BiTypeApp<'a, X, Y>;
 }
 
+/// A witness that `F: BiTypeApp<'a, X, Y>` holds, in the same way as
+/// [TypeAppWitness](crate::type_app::TypeAppWitness) for unary
+/// type applications.
+pub trait BiTypeAppWitness<'a, F, X: 'a, Y: 'a>
+{
+  type Witness: BiTypeApp<'a, X, Y> + Refl<Refl = F>;
+}
+
+impl<'a, F, X: 'a, Y: 'a> BiTypeAppWitness<'a, F, X, Y> for F
+where
+  F: BiTypeApp<'a, X, Y>,
+{
+  type Witness = Self;
+}
+
+/// Recover the `F: BiTypeApp<'a, X, Y>` constraint from a witness
+/// and use it to run `cont`.
+pub fn with_bi_type_app_witness<'a, W, Cont, F: 'a, X: 'a, Y: 'a, R: 'a>(
+  _: W,
+  cont: Cont,
+) -> R
+where
+  W: BiTypeAppWitness<'a, F, X, Y>,
+  Cont: BiTypeAppGenericCont<'a, F, X, Y, R>,
+{
+  trait ReflCont<'a, Cont, X: 'a, Y: 'a, R: 'a>: Refl
+  where
+    Self::Refl: Sized + 'a,
+  {
+    type ContRefl: BiTypeAppGenericCont<'a, Self::Refl, X, Y, R>;
+
+    fn refl_cont(cont: Cont) -> Self::ContRefl;
+  }
+
+  impl<'a, Cont, F: 'a, X: 'a, Y: 'a, R: 'a> ReflCont<'a, Cont, X, Y, R> for F
+  where
+    Cont: BiTypeAppGenericCont<'a, F, X, Y, R>,
+  {
+    type ContRefl = Cont;
+
+    fn refl_cont(cont: Cont) -> Cont
+    {
+      cont
+    }
+  }
+
+  fn inner_1<'a, Cont, W, F1, F2, X: 'a, Y: 'a, R: 'a>(
+    _: W,
+    cont: Cont,
+  ) -> R
+  where
+    W: HasReflUnbounded<F1, F2>,
+    W::Left: ReflCont<'a, Cont, X, Y, R> + 'a,
+    W::Right: BiTypeApp<'a, X, Y> + Sized,
+  {
+    W::Left::refl_cont(cont).on_type_app()
+  }
+
+  fn inner_2<'a, Cont, F: 'a, G: 'a, X: 'a, Y: 'a, R: 'a>(cont: Cont) -> R
+  where
+    G: BiTypeApp<'a, X, Y> + Refl<Refl = F>,
+    Cont: BiTypeAppGenericCont<'a, F, X, Y, R>,
+  {
+    inner_1::<'a, Cont, _, F, G, X, Y, R>(
+      refl_symmetric(has_refl::<G, F>()),
+      cont,
+    )
+  }
+
+  inner_2::<'a, Cont, F, W::Witness, X, Y, R>(cont)
+}
+
 pub trait HasBiTypeApp<'a, F: 'a + ?Sized, X: 'a + ?Sized, Y: 'a + ?Sized>
 {
   fn get_applied_box(self: Box<Self>) -> Box<F::Applied>
@@ -44,41 +124,74 @@ pub trait HasBiTypeApp<'a, F: 'a + ?Sized, X: 'a + ?Sized, Y: 'a + ?Sized>
 
 pub type BiApp<'a, F, X, Y> = Box<dyn HasBiTypeApp<'a, F, X, Y> + 'a>;
 
+/// A thread safe variant of [BiApp] that can be sent to another
+/// thread, constructed with [wrap_bi_app_send]. It coerces into
+/// a regular [BiApp] by forgetting the [Send] bound.
+pub type SendBiApp<'a, F, X, Y> =
+  Box<dyn HasBiTypeApp<'a, F, X, Y> + Send + 'a>;
+
+/// A variant of [BiApp] that is both [Send] and [Sync],
+/// constructed with [wrap_bi_app_sync].
+pub type SyncBiApp<'a, F, X, Y> =
+  Box<dyn HasBiTypeApp<'a, F, X, Y> + Send + Sync + 'a>;
+
 pub trait ToBiTypeApp<'a, F: 'a + ?Sized, X: 'a + ?Sized, Y: 'a + ?Sized>
 {
   fn to_applied(self: Box<Self>) -> BiApp<'a, F, X, Y>;
 }
 
-pub fn wrap_bi_app<'a, F: 'a, X: 'a, Y: 'a, FX: 'a>(
-  fx: FX
-) -> BiApp<'a, F, X, Y>
+/// The applied type `FX` behind the trait objects created by
+/// [wrap_bi_app] and its thread safe variants.
+struct Wrapped<FX>(FX);
+
+impl<'a, F: 'a + ?Sized, X: 'a + ?Sized, Y: 'a + ?Sized, FX: 'a>
+  HasBiTypeApp<'a, F, X, Y> for Wrapped<FX>
 where
   F: BiTypeApp<'a, X, Y, Applied = FX>,
 {
-  struct Applied<FX>(FX);
-
-  impl<'a, F: 'a + ?Sized, X: 'a + ?Sized, Y: 'a + ?Sized, FX: 'a>
-    HasBiTypeApp<'a, F, X, Y> for Applied<FX>
-  where
-    F: BiTypeApp<'a, X, Y, Applied = FX>,
+  fn get_applied_box(self: Box<Self>) -> Box<FX>
   {
-    fn get_applied_box(self: Box<Self>) -> Box<FX>
-    {
-      Box::new(self.0)
-    }
+    Box::new(self.0)
+  }
 
-    fn get_applied_borrow(&self) -> &FX
-    {
-      &self.0
-    }
+  fn get_applied_borrow(&self) -> &FX
+  {
+    &self.0
+  }
 
-    fn get_applied_borrow_mut(&mut self) -> &mut FX
-    {
-      &mut self.0
-    }
+  fn get_applied_borrow_mut(&mut self) -> &mut FX
+  {
+    &mut self.0
   }
+}
 
-  Box::new(Applied(fx))
+pub fn wrap_bi_app<'a, F: 'a, X: 'a + ?Sized, Y: 'a + ?Sized, FX: 'a>(
+  fx: FX
+) -> BiApp<'a, F, X, Y>
+where
+  F: BiTypeApp<'a, X, Y, Applied = FX>,
+{
+  Box::new(Wrapped(fx))
+}
+
+pub fn wrap_bi_app_send<'a, F: 'a, X: 'a + ?Sized, Y: 'a + ?Sized, FX: 'a>(
+  fx: FX
+) -> SendBiApp<'a, F, X, Y>
+where
+  F: BiTypeApp<'a, X, Y, Applied = FX>,
+  FX: Send,
+{
+  Box::new(Wrapped(fx))
+}
+
+pub fn wrap_bi_app_sync<'a, F: 'a, X: 'a + ?Sized, Y: 'a + ?Sized, FX: 'a>(
+  fx: FX
+) -> SyncBiApp<'a, F, X, Y>
+where
+  F: BiTypeApp<'a, X, Y, Applied = FX>,
+  FX: Send + Sync,
+{
+  Box::new(Wrapped(fx))
 }
 
 /// Partial application of a binary type constructor
@@ -97,6 +210,34 @@ where
   type Applied = F::Applied;
 }
 
+impl<F, A> TypeAppGeneric for Partial<F, A>
+where
+  F: BiTypeAppGeneric,
+{
+  fn with_type_app<'a, B: 'a, R: 'a, Cont: 'a>(cont: Cont) -> R
+  where
+    Self: 'a,
+    Cont: TypeAppCont<'a, Self, B, R>,
+  {
+    struct PartialCont<Cont, A>(Cont, PhantomData<A>);
+
+    impl<'a, F: 'a, A: 'a, B: 'a, R: 'a, Cont>
+      BiTypeAppGenericCont<'a, F, A, B, R> for PartialCont<Cont, A>
+    where
+      Cont: TypeAppCont<'a, Partial<F, A>, B, R>,
+    {
+      fn on_type_app(self) -> R
+      where
+        F: BiTypeApp<'a, A, B>,
+      {
+        self.0.on_type_app()
+      }
+    }
+
+    F::with_type_app::<'a, A, B, R>(PartialCont(cont, PhantomData))
+  }
+}
+
 pub struct Curry<F>(PhantomData<F>);
 
 impl<F: BiTypeCon> TypeCon for Curry<F> {}
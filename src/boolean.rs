@@ -0,0 +1,105 @@
+//! Type-level booleans and conditionals.
+//!
+//! [True] and [False] are the two type-level booleans, with the
+//! type functions [Not], [And], [Or] and [If] defined as type aliases
+//! over the traits [LogicalNot], [LogicalAnd], [LogicalOr] and
+//! [Conditional]. A type-level boolean can be reified into a `bool`
+//! with [Bool::VALUE].
+//!
+//! ```
+//! # use lambek::boolean::*;
+//! type Flag = Or<False, Not<False>>;
+//!
+//! let _: If<Flag, u32, String> = 42;
+//!
+//! assert!(Flag::VALUE);
+//! assert!(!<And<Flag, False>>::VALUE);
+//! ```
+
+pub enum True {}
+pub enum False {}
+
+pub trait Bool
+{
+  /// The reified value of the boolean.
+  const VALUE: bool;
+}
+
+/// Type-level negation, `!Self`.
+pub trait LogicalNot: Bool
+{
+  type Output: Bool;
+}
+
+/// Type-level conjunction, `Self && B`.
+pub trait LogicalAnd<B: Bool>: Bool
+{
+  type Output: Bool;
+}
+
+/// Type-level disjunction, `Self || B`.
+pub trait LogicalOr<B: Bool>: Bool
+{
+  type Output: Bool;
+}
+
+/// Type-level conditional, `if Self { Then } else { Else }`.
+pub trait Conditional<Then: ?Sized, Else: ?Sized>: Bool
+{
+  type Output: ?Sized;
+}
+
+pub type Not<B> = <B as LogicalNot>::Output;
+pub type And<B1, B2> = <B1 as LogicalAnd<B2>>::Output;
+pub type Or<B1, B2> = <B1 as LogicalOr<B2>>::Output;
+pub type If<C, Then, Else> = <C as Conditional<Then, Else>>::Output;
+
+impl Bool for True
+{
+  const VALUE: bool = true;
+}
+
+impl Bool for False
+{
+  const VALUE: bool = false;
+}
+
+impl LogicalNot for True
+{
+  type Output = False;
+}
+
+impl LogicalNot for False
+{
+  type Output = True;
+}
+
+impl<B: Bool> LogicalAnd<B> for True
+{
+  type Output = B;
+}
+
+impl<B: Bool> LogicalAnd<B> for False
+{
+  type Output = False;
+}
+
+impl<B: Bool> LogicalOr<B> for True
+{
+  type Output = True;
+}
+
+impl<B: Bool> LogicalOr<B> for False
+{
+  type Output = B;
+}
+
+impl<Then: ?Sized, Else: ?Sized> Conditional<Then, Else> for True
+{
+  type Output = Then;
+}
+
+impl<Then: ?Sized, Else: ?Sized> Conditional<Then, Else> for False
+{
+  type Output = Else;
+}
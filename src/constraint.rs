@@ -1,3 +1,45 @@
+//! Constraint kinds, `Type -> Constraint`.
+//!
+//! A constraint `C: HasConstraint<X>` is a proof that the type `X`
+//! satisfies some trait bound, such as `X: Display`. The proof is
+//! used by passing a continuation of type `BiApp<'a, C, X, R>`, which
+//! is given access to the bound when it is called.
+//!
+//! The [define_constraint](crate::define_constraint!) macro generates
+//! a constraint proxy for any trait, and this module provides the stock
+//! constraints for the common standard traits. A proof can also be
+//! reified into a value [`Dict<C, X>`](Dict), which can be stored and
+//! passed around without carrying the trait bound on `X`:
+//!
+//! ```
+//! # use lambek::constraint::*;
+//! # use core::fmt::Debug;
+//! struct Cont<'a, X>(&'a X);
+//!
+//! impl<'a, X> DebugCont<X, String> for Cont<'a, X>
+//! {
+//!   fn on_debug(self: Box<Self>) -> String
+//!   where
+//!     X: Debug,
+//!   {
+//!     format!("{:?}", self.0)
+//!   }
+//! }
+//!
+//! let dict: Dict<DebugConstraint, Vec<u32>> = Dict::new();
+//! let xs = vec![1, 2, 3];
+//!
+//! let shown = dict.with_constraint(DebugConstraint::wrap_cont(Cont(&xs)));
+//! assert_eq!(shown, "[1, 2, 3]");
+//! ```
+//!
+//! Constraints can be combined with [Both], and [Entails] extracts
+//! the superclass constraints of a constraint, such as
+//! `OrdConstraint => EqConstraint`. [Trivial] is the constraint that
+//! is satisfied by all types.
+
+use core::marker::PhantomData;
+
 use crate::bi_type_app::*;
 
 pub trait HasConstraint<X: ?Sized>: BiTypeCon
@@ -7,3 +49,496 @@ pub trait HasConstraint<X: ?Sized>: BiTypeCon
     X: 'a,
     Self: 'a;
 }
+
+/// A constraint proxy whose continuations can have their results
+/// mapped over, which lets [Dict] call a continuation with its result
+/// type erased. This is implemented by
+/// [define_constraint](crate::define_constraint!) for all `X`.
+pub trait MapCont<X: ?Sized>: BiTypeCon
+{
+  fn map_cont<'a, 'b, R: 'a, S: 'b>(
+    cont: BiApp<'a, Self, X, R>,
+    mapper: impl FnOnce(R) -> S + 'b,
+  ) -> BiApp<'b, Self, X, S>
+  where
+    X: 'a,
+    Self: 'a,
+    'a: 'b;
+}
+
+/// A constraint proxy with a continuation that reifies the constraint
+/// on `X` into a [Dict], so that a proof of `Self` can be obtained
+/// from within the continuation of another constraint. This is
+/// implemented by [define_constraint](crate::define_constraint!) for
+/// all `X`.
+pub trait ReifyDict<X: ?Sized>: BiTypeCon + Sized
+{
+  fn reify_with<'a, R: 'a>(
+    cont: impl FnOnce(Dict<Self, X>) -> R + 'a
+  ) -> BiApp<'a, Self, X, R>
+  where
+    X: 'a,
+    Self: 'a;
+}
+
+/// A reified proof of `C: HasConstraint<X>`.
+///
+/// A `Dict` can only be constructed where `C: HasConstraint<X>` holds,
+/// but can then be used without the bound.
+pub struct Dict<C, X: ?Sized>
+{
+  proof: for<'a> fn(BiApp<'a, C, X, ()>, Outlives<'a, C, X>),
+}
+
+/// Carries the implied bounds `C: 'a` and `X: 'a`, so that the
+/// higher-ranked function pointer in [Dict] can call
+/// `C::with_constraint` for any `'a`.
+type Outlives<'a, C, X> = PhantomData<(&'a C, &'a X)>;
+
+fn with_unit<'a, C, X: ?Sized>(
+  cont: BiApp<'a, C, X, ()>,
+  _: Outlives<'a, C, X>,
+) where
+  C: HasConstraint<X>,
+{
+  C::with_constraint(cont)
+}
+
+impl<C, X: ?Sized> Dict<C, X>
+{
+  pub fn new() -> Self
+  where
+    C: HasConstraint<X>,
+  {
+    Dict {
+      proof: with_unit::<C, X>,
+    }
+  }
+
+  /// Call the continuation with the constraint `C` on `X`.
+  pub fn with_constraint<'a, R: 'a>(
+    &self,
+    cont: BiApp<'a, C, X, R>,
+  ) -> R
+  where
+    X: 'a,
+    C: MapCont<X> + 'a,
+  {
+    let mut slot = None;
+    let erased = C::map_cont(cont, |r| {
+      slot = Some(r);
+    });
+
+    (self.proof)(erased, PhantomData);
+    slot.unwrap()
+  }
+}
+
+impl<C, X: ?Sized> Dict<C, X>
+{
+  /// Extract the dictionary of a constraint `C2` entailed by `C`,
+  /// e.g. `Dict<EqConstraint, X>` from `Dict<OrdConstraint, X>`.
+  pub fn entail<C2>(&self) -> Dict<C2, X>
+  where
+    C: Entails<C2> + MapCont<X>,
+    C2: ReifyDict<X>,
+  {
+    self.with_constraint(C::entail(C2::reify_with(|dict| dict)))
+  }
+}
+
+impl<C1, C2, X: ?Sized> Dict<Both<C1, C2>, X>
+{
+  /// Split the dictionary of a conjunction into the dictionaries of
+  /// both constraints.
+  pub fn split(&self) -> (Dict<C1, X>, Dict<C2, X>)
+  where
+    C1: ReifyDict<X> + MapCont<X>,
+    C2: ReifyDict<X> + MapCont<X>,
+  {
+    self.with_constraint(Both::wrap_cont(C1::reify_with(|dict1| {
+      C2::reify_with(move |dict2| (dict1, dict2))
+    })))
+  }
+}
+
+impl<C, X: ?Sized> Default for Dict<C, X>
+where
+  C: HasConstraint<X>,
+{
+  fn default() -> Self
+  {
+    Dict::new()
+  }
+}
+
+impl<C, X: ?Sized> Clone for Dict<C, X>
+{
+  fn clone(&self) -> Self
+  {
+    *self
+  }
+}
+
+impl<C, X: ?Sized> Copy for Dict<C, X> {}
+
+/// Define a constraint proxy for a trait, e.g.
+/// `define_constraint!(pub DebugConstraint, DebugCont, on_debug, Debug)`.
+///
+/// This generates the continuation trait `DebugCont<X, R>` with the
+/// method `on_debug` that has access to `X: Debug`, the proxy type
+/// `DebugConstraint` that implements [HasConstraint] for all
+/// `X: Debug`, and the inherent functions `DebugConstraint::wrap_cont`
+/// and `DebugConstraint::with_cont` for calling a continuation.
+#[macro_export]
+macro_rules! define_constraint {
+  ( $vis:vis $proxy:ident, $cont:ident, $method:ident, $bound:path $(,)? ) => {
+    $vis enum $proxy {}
+
+    $vis trait $cont<X: ?Sized, R>
+    {
+      fn $method(self: Box<Self>) -> R
+      where
+        X: $bound;
+    }
+
+    impl $crate::bi_type_app::BiTypeCon for $proxy {}
+
+    impl<'a, X: 'a + ?Sized, R: 'a> $crate::bi_type_app::BiTypeApp<'a, X, R>
+      for $proxy
+    {
+      type Applied = dyn $cont<X, R> + 'a;
+    }
+
+    impl $crate::bi_type_app::BiTypeAppGeneric for $proxy
+    {
+      fn with_type_app<'a, X: 'a, R: 'a, K: 'a>(
+        cont: impl $crate::bi_type_app::BiTypeAppGenericCont<'a, Self, X, R, K>
+      ) -> K
+      where
+        Self: 'a,
+      {
+        cont.on_type_app()
+      }
+    }
+
+    impl<X: ?Sized + $bound> $crate::constraint::HasConstraint<X> for $proxy
+    {
+      fn with_constraint<'a, R: 'a>(
+        cont: $crate::bi_type_app::BiApp<'a, Self, X, R>
+      ) -> R
+      where
+        X: 'a,
+      {
+        cont.get_applied_box().$method()
+      }
+    }
+
+    impl<X: ?Sized> $crate::constraint::MapCont<X> for $proxy
+    {
+      fn map_cont<'a, 'b, R: 'a, S: 'b>(
+        cont: $crate::bi_type_app::BiApp<'a, Self, X, R>,
+        mapper: impl FnOnce(R) -> S + 'b,
+      ) -> $crate::bi_type_app::BiApp<'b, Self, X, S>
+      where
+        X: 'a,
+        'a: 'b,
+      {
+        struct Mapped<'a, X: ?Sized, R, F>
+        {
+          cont: Box<dyn $cont<X, R> + 'a>,
+          mapper: F,
+        }
+
+        impl<'a, X: ?Sized, R, S, F> $cont<X, S> for Mapped<'a, X, R, F>
+        where
+          F: FnOnce(R) -> S,
+        {
+          fn $method(self: Box<Self>) -> S
+          where
+            X: $bound,
+          {
+            let this = *self;
+            (this.mapper)(this.cont.$method())
+          }
+        }
+
+        $proxy::wrap_cont(Mapped {
+          cont: cont.get_applied_box(),
+          mapper,
+        })
+      }
+    }
+
+    impl<X: ?Sized> $crate::constraint::ReifyDict<X> for $proxy
+    {
+      fn reify_with<'a, R: 'a>(
+        cont: impl FnOnce($crate::constraint::Dict<Self, X>) -> R + 'a
+      ) -> $crate::bi_type_app::BiApp<'a, Self, X, R>
+      where
+        X: 'a,
+      {
+        struct Reify<F>(F);
+
+        impl<X: ?Sized, R, F> $cont<X, R> for Reify<F>
+        where
+          F: FnOnce($crate::constraint::Dict<$proxy, X>) -> R,
+        {
+          fn $method(self: Box<Self>) -> R
+          where
+            X: $bound,
+          {
+            (self.0)($crate::constraint::Dict::new())
+          }
+        }
+
+        $proxy::wrap_cont(Reify(cont))
+      }
+    }
+
+    impl $proxy
+    {
+      /// Wrap a continuation into a [BiApp] of this constraint.
+      #[allow(dead_code)]
+      $vis fn wrap_cont<'a, Cont: 'a, X: 'a + ?Sized, R: 'a>(
+        cont: Cont
+      ) -> $crate::bi_type_app::BiApp<'a, $proxy, X, R>
+      where
+        Cont: $cont<X, R>,
+      {
+        struct Applied<Cont>(Cont);
+
+        impl<'a, Cont: 'a, X: 'a + ?Sized, R: 'a>
+          $crate::bi_type_app::HasBiTypeApp<'a, $proxy, X, R> for Applied<Cont>
+        where
+          Cont: $cont<X, R>,
+          $proxy: $crate::bi_type_app::BiTypeApp<
+            'a,
+            X,
+            R,
+            Applied = dyn $cont<X, R> + 'a,
+          >,
+        {
+          fn get_applied_box(self: Box<Self>) -> Box<dyn $cont<X, R> + 'a>
+          {
+            Box::new(self.0)
+          }
+
+          fn get_applied_borrow(&self) -> &(dyn $cont<X, R> + 'a)
+          {
+            &self.0
+          }
+
+          fn get_applied_borrow_mut(&mut self) -> &mut (dyn $cont<X, R> + 'a)
+          {
+            &mut self.0
+          }
+        }
+
+        Box::new(Applied(cont))
+      }
+
+      /// Call the continuation with the constraint on `X`.
+      #[allow(dead_code)]
+      $vis fn with_cont<'a, X: 'a + ?Sized, R: 'a>(
+        cont: impl $cont<X, R> + 'a
+      ) -> R
+      where
+        $proxy: $crate::constraint::HasConstraint<X>,
+      {
+        <$proxy as $crate::constraint::HasConstraint<X>>::with_constraint(
+          $proxy::wrap_cont(cont),
+        )
+      }
+    }
+  };
+}
+
+define_constraint!(pub DebugConstraint, DebugCont, on_debug, core::fmt::Debug);
+
+define_constraint!(
+  pub DisplayConstraint,
+  DisplayCont,
+  on_display,
+  core::fmt::Display
+);
+
+define_constraint!(pub CloneConstraint, CloneCont, on_clone, Clone);
+
+define_constraint!(
+  pub PartialEqConstraint,
+  PartialEqCont,
+  on_partial_eq,
+  PartialEq
+);
+
+define_constraint!(
+  pub PartialOrdConstraint,
+  PartialOrdCont,
+  on_partial_ord,
+  PartialOrd
+);
+
+define_constraint!(pub EqConstraint, EqCont, on_eq, Eq);
+
+define_constraint!(pub OrdConstraint, OrdCont, on_ord, Ord);
+
+define_constraint!(pub HashConstraint, HashCont, on_hash, core::hash::Hash);
+
+define_constraint!(pub DefaultConstraint, DefaultCont, on_default, Default);
+
+define_constraint!(pub SendConstraint, SendCont, on_send, Send);
+
+define_constraint!(pub SyncConstraint, SyncCont, on_sync, Sync);
+
+/// A marker trait implemented by all types, for the [Trivial]
+/// constraint.
+pub trait Always {}
+
+impl<X: ?Sized> Always for X {}
+
+define_constraint!(pub Trivial, TrivialCont, on_trivial, Always);
+
+/// The conjunction of the constraints `C1` and `C2`.
+///
+/// A continuation of `Both<C1, C2>` is a continuation of `C1` that
+/// returns a continuation of `C2`. The inner continuation can rely on
+/// both constraints by having its continuation trait implemented with
+/// the bound of `C1` on the `impl`.
+pub struct Both<C1: ?Sized, C2: ?Sized>(PhantomData<C1>, PhantomData<C2>);
+
+impl<C1: ?Sized, C2: ?Sized> BiTypeCon for Both<C1, C2> {}
+
+impl<'a, C1: 'a, C2: 'a, X: 'a + ?Sized, R: 'a> BiTypeApp<'a, X, R>
+  for Both<C1, C2>
+{
+  type Applied = BiApp<'a, C1, X, BiApp<'a, C2, X, R>>;
+}
+
+impl<C1, C2> Both<C1, C2>
+{
+  pub fn wrap_cont<'a, X: 'a + ?Sized, R: 'a>(
+    cont: BiApp<'a, C1, X, BiApp<'a, C2, X, R>>
+  ) -> BiApp<'a, Both<C1, C2>, X, R>
+  where
+    C1: 'a,
+    C2: 'a,
+  {
+    wrap_bi_app(cont)
+  }
+}
+
+impl<C1, C2, X: ?Sized> HasConstraint<X> for Both<C1, C2>
+where
+  C1: HasConstraint<X>,
+  C2: HasConstraint<X>,
+{
+  fn with_constraint<'a, R: 'a>(cont: BiApp<'a, Self, X, R>) -> R
+  where
+    X: 'a,
+    Self: 'a,
+  {
+    C2::with_constraint(C1::with_constraint(*cont.get_applied_box()))
+  }
+}
+
+impl<C1, C2, X: ?Sized> MapCont<X> for Both<C1, C2>
+where
+  C1: MapCont<X>,
+  C2: MapCont<X>,
+{
+  fn map_cont<'a, 'b, R: 'a, S: 'b>(
+    cont: BiApp<'a, Self, X, R>,
+    mapper: impl FnOnce(R) -> S + 'b,
+  ) -> BiApp<'b, Self, X, S>
+  where
+    X: 'a,
+    Self: 'a,
+    'a: 'b,
+  {
+    Both::wrap_cont(C1::map_cont(*cont.get_applied_box(), move |cont2| {
+      C2::map_cont(cont2, mapper)
+    }))
+  }
+}
+
+/// Constraint entailment, `Self => C`, i.e. every type satisfying
+/// `Self` also satisfies `C`. This is typically due to `C` being a
+/// supertrait of `Self`, such as `Ord => PartialOrd => PartialEq`.
+///
+/// A continuation that requires `C` can be turned into a continuation
+/// that requires `Self`.
+pub trait Entails<C>: BiTypeCon
+{
+  fn entail<'a, X: 'a + ?Sized, R: 'a>(
+    cont: BiApp<'a, C, X, R>
+  ) -> BiApp<'a, Self, X, R>
+  where
+    Self: 'a,
+    C: 'a;
+}
+
+impl<C: BiTypeCon> Entails<C> for C
+{
+  fn entail<'a, X: 'a + ?Sized, R: 'a>(
+    cont: BiApp<'a, C, X, R>
+  ) -> BiApp<'a, C, X, R>
+  where
+    C: 'a,
+  {
+    cont
+  }
+}
+
+macro_rules! impl_entails {
+  ( $proxy:ident, $cont:ident, $method:ident, $bound:path
+    => $super_proxy:ident, $super_cont:ident, $super_method:ident
+  ) => {
+    impl Entails<$super_proxy> for $proxy
+    {
+      fn entail<'a, X: 'a + ?Sized, R: 'a>(
+        cont: BiApp<'a, $super_proxy, X, R>
+      ) -> BiApp<'a, $proxy, X, R>
+      {
+        struct Entailed<'a, X: ?Sized, R>(Box<dyn $super_cont<X, R> + 'a>);
+
+        impl<'a, X: ?Sized, R> $cont<X, R> for Entailed<'a, X, R>
+        {
+          fn $method(self: Box<Self>) -> R
+          where
+            X: $bound,
+          {
+            self.0.$super_method()
+          }
+        }
+
+        $proxy::wrap_cont(Entailed(cont.get_applied_box()))
+      }
+    }
+  };
+}
+
+impl_entails!(
+  OrdConstraint, OrdCont, on_ord, Ord
+  => PartialOrdConstraint, PartialOrdCont, on_partial_ord
+);
+
+impl_entails!(
+  OrdConstraint, OrdCont, on_ord, Ord
+  => EqConstraint, EqCont, on_eq
+);
+
+impl_entails!(
+  OrdConstraint, OrdCont, on_ord, Ord
+  => PartialEqConstraint, PartialEqCont, on_partial_eq
+);
+
+impl_entails!(
+  PartialOrdConstraint, PartialOrdCont, on_partial_ord, PartialOrd
+  => PartialEqConstraint, PartialEqCont, on_partial_eq
+);
+
+impl_entails!(
+  EqConstraint, EqCont, on_eq, Eq
+  => PartialEqConstraint, PartialEqCont, on_partial_eq
+);
@@ -1,9 +1,67 @@
+use core::marker::PhantomData;
+
 use crate::bi_type_app::*;
 
 pub enum FunctionF {}
 pub enum FunctionMutF {}
 pub enum FunctionOnceF {}
 
+/// Function proxies whose applied types are concrete types instead
+/// of `dyn Fn` trait objects.
+///
+/// The applied type of `FnPtrF` is the plain function pointer
+/// `fn(A) -> B`, and the applied type of `ClosureF<C>` is a [Closure]
+/// holding the closure `C` itself together with a function pointer
+/// to its call method. `C` is typically the unnameable type of a
+/// closure expression, which is inferred by [wrap_closure].
+///
+/// These proxies do not make a call cheaper than with [FunctionF].
+/// A mapper is still a [BiApp] trait object, so each call first goes
+/// through its vtable to borrow the applied value, and then calls
+/// the function pointer, which is as indirect as calling a `dyn Fn`.
+/// Whether the calls are inlined is up to the optimizer. What they
+/// provide instead is an applied type that can be named and that
+/// does not borrow from the [BiApp] holding it, so that it can be
+/// moved out of a short-lived mapper and kept for longer, as is done
+/// by [Coyoneda](crate::functor::Coyoneda).
+///
+/// Since the methods of [IsFn] are generic over the lifetime of the
+/// applied type, the closure proxies only implement them when the
+/// closure type `C` is `'static`, i.e. when it only captures owned
+/// values. Closures that borrow from their environment can use
+/// [FunctionF] instead.
+///
+/// Unlike the [FunctionF] family, these proxies do not implement
+/// [WrapFn], as the closures given to [WrapFn::wrap_fn] can neither
+/// be converted to a function pointer nor to the closure type `C`.
+pub enum FnPtrF {}
+pub struct ClosureF<C>(PhantomData<C>);
+pub struct ClosureMutF<C>(PhantomData<C>);
+pub struct ClosureOnceF<C>(PhantomData<C>);
+
+/// The applied type of [ClosureF], [ClosureMutF] and [ClosureOnceF],
+/// a closure `C` stored unboxed alongside the monomorphised function
+/// `Call` that invokes it.
+pub struct Closure<C, Call>
+{
+  closure: C,
+  call: Call,
+}
+
+/// Thread safe variants of [FunctionF], [FunctionMutF] and
+/// [FunctionOnceF], applied to closures that can be sent to another
+/// thread. The `Fn` variant is also [Sync], so that a single mapper
+/// can be shared by several threads.
+///
+/// These proxies implement [IsFn], [IsFnMut] and [IsFnOnce], so
+/// [Functor](crate::functor::Functor) and the other type classes
+/// that are generic over the function proxy can be used with them.
+/// They do not implement [WrapFn], which cannot require the
+/// closures it wraps to be `Send`.
+pub enum SendFunctionF {}
+pub enum SendFunctionMutF {}
+pub enum SendFunctionOnceF {}
+
 pub trait IsFnOnce: BiTypeCon
 {
   fn apply_once<'a, A: 'a, B: 'a>(
@@ -28,6 +86,18 @@ pub trait IsFn: IsFnMut
   ) -> B;
 }
 
+/// Function proxies that can be constructed from any `Fn` closure.
+///
+/// This allows generic code to produce mappers for a function
+/// proxy `Func` that it is parameterized over, as long as the
+/// mapper do not capture anything that requires `FnMut` or `FnOnce`.
+pub trait WrapFn: BiTypeCon
+{
+  fn wrap_fn<'a, F: 'a, A: 'a, B: 'a>(f: F) -> BiApp<'a, Self, A, B>
+  where
+    F: Fn(A) -> B;
+}
+
 impl BiTypeCon for FunctionF {}
 
 impl<'a, A: 'a, B: 'a> BiTypeApp<'a, A, B> for FunctionF
@@ -49,6 +119,55 @@ impl<'a, A: 'a, B: 'a> BiTypeApp<'a, A, B> for FunctionOnceF
   type Applied = dyn FnOnce(A) -> B + 'a;
 }
 
+impl BiTypeCon for SendFunctionF {}
+
+impl<'a, A: 'a, B: 'a> BiTypeApp<'a, A, B> for SendFunctionF
+{
+  type Applied = dyn Fn(A) -> B + Send + Sync + 'a;
+}
+
+impl BiTypeCon for SendFunctionMutF {}
+
+impl<'a, A: 'a, B: 'a> BiTypeApp<'a, A, B> for SendFunctionMutF
+{
+  type Applied = dyn FnMut(A) -> B + Send + 'a;
+}
+
+impl BiTypeCon for SendFunctionOnceF {}
+
+impl<'a, A: 'a, B: 'a> BiTypeApp<'a, A, B> for SendFunctionOnceF
+{
+  type Applied = dyn FnOnce(A) -> B + Send + 'a;
+}
+
+impl BiTypeCon for FnPtrF {}
+
+impl<'a, A: 'a, B: 'a> BiTypeApp<'a, A, B> for FnPtrF
+{
+  type Applied = fn(A) -> B;
+}
+
+impl<C> BiTypeCon for ClosureF<C> {}
+
+impl<'a, C: 'a, A: 'a, B: 'a> BiTypeApp<'a, A, B> for ClosureF<C>
+{
+  type Applied = Closure<C, fn(&C, A) -> B>;
+}
+
+impl<C> BiTypeCon for ClosureMutF<C> {}
+
+impl<'a, C: 'a, A: 'a, B: 'a> BiTypeApp<'a, A, B> for ClosureMutF<C>
+{
+  type Applied = Closure<C, fn(&mut C, A) -> B>;
+}
+
+impl<C> BiTypeCon for ClosureOnceF<C> {}
+
+impl<'a, C: 'a, A: 'a, B: 'a> BiTypeApp<'a, A, B> for ClosureOnceF<C>
+{
+  type Applied = Closure<C, fn(C, A) -> B>;
+}
+
 impl IsFn for FunctionF
 {
   fn apply<'a, A: 'a, B: 'a>(
@@ -115,6 +234,207 @@ impl IsFnOnce for FunctionOnceF
   }
 }
 
+impl IsFn for SendFunctionF
+{
+  fn apply<'a, A: 'a, B: 'a>(
+    f: &BiApp<'a, Self, A, B>,
+    a: A,
+  ) -> B
+  {
+    f.get_applied_borrow()(a)
+  }
+}
+
+impl IsFnMut for SendFunctionF
+{
+  fn apply_mut<'a, A: 'a, B: 'a>(
+    f: &mut BiApp<'a, Self, A, B>,
+    a: A,
+  ) -> B
+  {
+    f.get_applied_borrow()(a)
+  }
+}
+
+impl IsFnOnce for SendFunctionF
+{
+  fn apply_once<'a, A: 'a, B: 'a>(
+    f: BiApp<'a, Self, A, B>,
+    a: A,
+  ) -> B
+  {
+    f.get_applied_box()(a)
+  }
+}
+
+impl IsFnMut for SendFunctionMutF
+{
+  fn apply_mut<'a, A: 'a, B: 'a>(
+    f: &mut BiApp<'a, Self, A, B>,
+    a: A,
+  ) -> B
+  {
+    f.get_applied_borrow_mut()(a)
+  }
+}
+
+impl IsFnOnce for SendFunctionMutF
+{
+  fn apply_once<'a, A: 'a, B: 'a>(
+    f: BiApp<'a, Self, A, B>,
+    a: A,
+  ) -> B
+  {
+    f.get_applied_box()(a)
+  }
+}
+
+impl IsFnOnce for SendFunctionOnceF
+{
+  fn apply_once<'a, A: 'a, B: 'a>(
+    f: BiApp<'a, Self, A, B>,
+    a: A,
+  ) -> B
+  {
+    f.get_applied_box()(a)
+  }
+}
+
+impl IsFn for FnPtrF
+{
+  fn apply<'a, A: 'a, B: 'a>(
+    f: &BiApp<'a, Self, A, B>,
+    a: A,
+  ) -> B
+  {
+    f.get_applied_borrow()(a)
+  }
+}
+
+impl IsFnMut for FnPtrF
+{
+  fn apply_mut<'a, A: 'a, B: 'a>(
+    f: &mut BiApp<'a, Self, A, B>,
+    a: A,
+  ) -> B
+  {
+    f.get_applied_borrow()(a)
+  }
+}
+
+impl IsFnOnce for FnPtrF
+{
+  fn apply_once<'a, A: 'a, B: 'a>(
+    f: BiApp<'a, Self, A, B>,
+    a: A,
+  ) -> B
+  {
+    f.get_applied_borrow()(a)
+  }
+}
+
+impl<C: 'static> IsFn for ClosureF<C>
+{
+  fn apply<'a, A: 'a, B: 'a>(
+    f: &BiApp<'a, Self, A, B>,
+    a: A,
+  ) -> B
+  {
+    let f = f.get_applied_borrow();
+    (f.call)(&f.closure, a)
+  }
+}
+
+impl<C: 'static> IsFnMut for ClosureF<C>
+{
+  fn apply_mut<'a, A: 'a, B: 'a>(
+    f: &mut BiApp<'a, Self, A, B>,
+    a: A,
+  ) -> B
+  {
+    let f = f.get_applied_borrow();
+    (f.call)(&f.closure, a)
+  }
+}
+
+impl<C: 'static> IsFnOnce for ClosureF<C>
+{
+  fn apply_once<'a, A: 'a, B: 'a>(
+    f: BiApp<'a, Self, A, B>,
+    a: A,
+  ) -> B
+  {
+    let f = f.get_applied_box();
+    (f.call)(&f.closure, a)
+  }
+}
+
+impl<C: 'static> IsFnMut for ClosureMutF<C>
+{
+  fn apply_mut<'a, A: 'a, B: 'a>(
+    f: &mut BiApp<'a, Self, A, B>,
+    a: A,
+  ) -> B
+  {
+    let f = f.get_applied_borrow_mut();
+    (f.call)(&mut f.closure, a)
+  }
+}
+
+impl<C: 'static> IsFnOnce for ClosureMutF<C>
+{
+  fn apply_once<'a, A: 'a, B: 'a>(
+    f: BiApp<'a, Self, A, B>,
+    a: A,
+  ) -> B
+  {
+    let mut f = f.get_applied_box();
+    (f.call)(&mut f.closure, a)
+  }
+}
+
+impl<C: 'static> IsFnOnce for ClosureOnceF<C>
+{
+  fn apply_once<'a, A: 'a, B: 'a>(
+    f: BiApp<'a, Self, A, B>,
+    a: A,
+  ) -> B
+  {
+    let f = *f.get_applied_box();
+    (f.call)(f.closure, a)
+  }
+}
+
+impl WrapFn for FunctionF
+{
+  fn wrap_fn<'a, F: 'a, A: 'a, B: 'a>(f: F) -> BiApp<'a, Self, A, B>
+  where
+    F: Fn(A) -> B,
+  {
+    wrap_function(f)
+  }
+}
+
+impl WrapFn for FunctionMutF
+{
+  fn wrap_fn<'a, F: 'a, A: 'a, B: 'a>(f: F) -> BiApp<'a, Self, A, B>
+  where
+    F: Fn(A) -> B,
+  {
+    wrap_function_mut(f)
+  }
+}
+
+impl WrapFn for FunctionOnceF
+{
+  fn wrap_fn<'a, F: 'a, A: 'a, B: 'a>(f: F) -> BiApp<'a, Self, A, B>
+  where
+    F: Fn(A) -> B,
+  {
+    wrap_function_once(f)
+  }
+}
+
 pub fn wrap_function<'a, F: 'a, A: 'a, B: 'a>(
   f: F
 ) -> BiApp<'a, FunctionF, A, B>
@@ -212,3 +532,184 @@ where
 
   Box::new(Applied(f))
 }
+
+pub fn wrap_send_function<'a, F: 'a, A: 'a, B: 'a>(
+  f: F
+) -> SyncBiApp<'a, SendFunctionF, A, B>
+where
+  F: Fn(A) -> B + Send + Sync,
+{
+  struct Applied<F>(F);
+
+  impl<'a, F: 'a, A: 'a, B: 'a> HasBiTypeApp<'a, SendFunctionF, A, B>
+    for Applied<F>
+  where
+    F: Fn(A) -> B + Send + Sync,
+    SendFunctionF:
+      BiTypeApp<'a, A, B, Applied = dyn Fn(A) -> B + Send + Sync + 'a>,
+  {
+    fn get_applied_box(
+      self: Box<Self>
+    ) -> Box<dyn Fn(A) -> B + Send + Sync + 'a>
+    {
+      Box::new(self.0)
+    }
+
+    fn get_applied_borrow(&self) -> &(dyn Fn(A) -> B + Send + Sync + 'a)
+    {
+      &self.0
+    }
+
+    fn get_applied_borrow_mut(
+      &mut self
+    ) -> &mut (dyn Fn(A) -> B + Send + Sync + 'a)
+    {
+      &mut self.0
+    }
+  }
+
+  Box::new(Applied(f))
+}
+
+pub fn wrap_send_function_once<'a, F: 'a, A: 'a, B: 'a>(
+  f: F
+) -> SendBiApp<'a, SendFunctionOnceF, A, B>
+where
+  F: FnOnce(A) -> B + Send,
+{
+  struct Applied<F>(F);
+
+  impl<'a, F: 'a, A: 'a, B: 'a> HasBiTypeApp<'a, SendFunctionOnceF, A, B>
+    for Applied<F>
+  where
+    F: FnOnce(A) -> B + Send,
+    SendFunctionOnceF:
+      BiTypeApp<'a, A, B, Applied = dyn FnOnce(A) -> B + Send + 'a>,
+  {
+    fn get_applied_box(self: Box<Self>) -> Box<dyn FnOnce(A) -> B + Send + 'a>
+    {
+      Box::new(self.0)
+    }
+
+    fn get_applied_borrow(&self) -> &(dyn FnOnce(A) -> B + Send + 'a)
+    {
+      &self.0
+    }
+
+    fn get_applied_borrow_mut(
+      &mut self
+    ) -> &mut (dyn FnOnce(A) -> B + Send + 'a)
+    {
+      &mut self.0
+    }
+  }
+
+  Box::new(Applied(f))
+}
+
+pub fn wrap_send_function_mut<'a, F: 'a, A: 'a, B: 'a>(
+  f: F
+) -> SendBiApp<'a, SendFunctionMutF, A, B>
+where
+  F: FnMut(A) -> B + Send,
+{
+  struct Applied<F>(F);
+
+  impl<'a, F: 'a, A: 'a, B: 'a> HasBiTypeApp<'a, SendFunctionMutF, A, B>
+    for Applied<F>
+  where
+    F: FnMut(A) -> B + Send,
+    SendFunctionMutF:
+      BiTypeApp<'a, A, B, Applied = dyn FnMut(A) -> B + Send + 'a>,
+  {
+    fn get_applied_box(self: Box<Self>) -> Box<dyn FnMut(A) -> B + Send + 'a>
+    {
+      Box::new(self.0)
+    }
+
+    fn get_applied_borrow(&self) -> &(dyn FnMut(A) -> B + Send + 'a)
+    {
+      &self.0
+    }
+
+    fn get_applied_borrow_mut(
+      &mut self
+    ) -> &mut (dyn FnMut(A) -> B + Send + 'a)
+    {
+      &mut self.0
+    }
+  }
+
+  Box::new(Applied(f))
+}
+
+pub fn wrap_fn_ptr<'a, A: 'a, B: 'a>(f: fn(A) -> B) -> BiApp<'a, FnPtrF, A, B>
+{
+  wrap_bi_app(f)
+}
+
+pub fn wrap_closure<'a, C: 'a, A: 'a, B: 'a>(
+  closure: C
+) -> BiApp<'a, ClosureF<C>, A, B>
+where
+  C: Fn(A) -> B,
+{
+  fn call<C, A, B>(
+    closure: &C,
+    a: A,
+  ) -> B
+  where
+    C: Fn(A) -> B,
+  {
+    closure(a)
+  }
+
+  wrap_bi_app(Closure {
+    closure,
+    call: call::<C, A, B> as fn(&C, A) -> B,
+  })
+}
+
+pub fn wrap_closure_mut<'a, C: 'a, A: 'a, B: 'a>(
+  closure: C
+) -> BiApp<'a, ClosureMutF<C>, A, B>
+where
+  C: FnMut(A) -> B,
+{
+  fn call<C, A, B>(
+    closure: &mut C,
+    a: A,
+  ) -> B
+  where
+    C: FnMut(A) -> B,
+  {
+    closure(a)
+  }
+
+  wrap_bi_app(Closure {
+    closure,
+    call: call::<C, A, B> as fn(&mut C, A) -> B,
+  })
+}
+
+pub fn wrap_closure_once<'a, C: 'a, A: 'a, B: 'a>(
+  closure: C
+) -> BiApp<'a, ClosureOnceF<C>, A, B>
+where
+  C: FnOnce(A) -> B,
+{
+  fn call<C, A, B>(
+    closure: C,
+    a: A,
+  ) -> B
+  where
+    C: FnOnce(A) -> B,
+  {
+    closure(a)
+  }
+
+  wrap_bi_app(Closure {
+    closure,
+    call: call::<C, A, B> as fn(C, A) -> B,
+  })
+}
@@ -1,3 +1,13 @@
+use core::{
+  convert::TryFrom,
+  marker::PhantomData,
+  mem::{
+    self,
+    ManuallyDrop,
+  },
+  ptr,
+};
+
 use crate::{
   bi_type_app::*,
   function::*,
@@ -16,14 +26,78 @@ pub trait Functor<Func>: TypeCon
     'a: 'b;
 }
 
+/// An in-place variant of [Functor], which updates the applied
+/// value through
+/// [get_applied_borrow_mut](HasTypeApp::get_applied_borrow_mut)
+/// by applying a mutator to a mutable reference of each element,
+/// instead of consuming it and wrapping the result in a new [App].
+///
+/// Since no element is ever moved out of the applied type, it is
+/// left in a valid state even if the mutator panics.
+pub trait FunctorMut<Func>: TypeCon
+{
+  /// `fmapInPlace :: forall a . &mut f a -> (&mut a -> ()) -> ()`
+  fn fmap_in_place<'a, 'b, 'c, A: 'a>(
+    fa: &'c mut App<'a, Self, A>,
+    mapper: BiApp<'b, Func, &'c mut A, ()>,
+  ) where
+    Self: 'a,
+    'a: 'b,
+    'c: 'b;
+}
+
 pub trait Applicative<Func>: Functor<Func>
 {
-  fn pure<'a, A: 'a>(a: A) -> App<'a, Self, A>;
+  /// `pure :: forall a . a -> f a`
+  fn pure<'a, A: 'a>(a: A) -> App<'a, Self, A>
+  where
+    Self: 'a;
+
+  /// `apply :: forall a b . f (a -> b) -> f a -> f b`
+  fn apply<'a, A: 'a, B: 'a>(
+    fab: App<'a, Self, BiApp<'a, Func, A, B>>,
+    fa: App<'a, Self, A>,
+  ) -> App<'a, Self, B>
+  where
+    Self: 'a;
+
+  /// `liftA2 :: forall a b c . ((a, b) -> c) -> f a -> f b -> f c`
+  ///
+  /// The mapper takes its two arguments as a tuple, so that it can be
+  /// represented as a regular [BiApp] of the function proxy `Func`.
+  fn lift_a2<'a, 'b, A: 'a, B: 'a, C: 'a>(
+    fa: App<'a, Self, A>,
+    fb: App<'a, Self, B>,
+    mapper: BiApp<'b, Func, (A, B), C>,
+  ) -> App<'a, Self, C>
+  where
+    Self: 'a,
+    'a: 'b;
+}
+
+/// The [Applicative] operations without `pure`, for type constructors
+/// such as fixed size arrays that can combine values but cannot
+/// construct a value out of a single element.
+///
+/// `Apply` is not a supertrait of [Applicative], so that existing
+/// [Applicative] instances do not have to be split up. It is only
+/// implemented by the type constructors that are not [Applicative].
+pub trait Apply<Func>: Functor<Func>
+{
+  /// `apply :: forall a b . f (a -> b) -> f a -> f b`
+  fn apply<'a, A: 'a, B: 'a>(
+    fab: App<'a, Self, BiApp<'a, Func, A, B>>,
+    fa: App<'a, Self, A>,
+  ) -> App<'a, Self, B>
+  where
+    Self: 'a;
 
-  fn apply<'a, 'b, A: 'a, B: 'a, F: 'a>(
-    app: App<'a, Self, BiApp<'b, Func, A, B>>,
+  /// `liftA2 :: forall a b c . ((a, b) -> c) -> f a -> f b -> f c`
+  fn lift_a2<'a, 'b, A: 'a, B: 'a, C: 'a>(
     fa: App<'a, Self, A>,
-  ) -> App<'a, Self, A>
+    fb: App<'a, Self, B>,
+    mapper: BiApp<'b, Func, (A, B), C>,
+  ) -> App<'a, Self, C>
   where
     Self: 'a,
     'a: 'b;
@@ -40,6 +114,33 @@ pub trait Monad<Func>: Applicative<Func>
     'a: 'b;
 }
 
+pub trait Foldable<Func>: TypeCon
+{
+  /// `foldl :: forall a b . (b -> a -> b) -> b -> t a -> b`
+  fn fold_left<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    init: B,
+    folder: BiApp<'b, Func, (B, A), B>,
+  ) -> B
+  where
+    Self: 'a,
+    'a: 'b;
+}
+
+pub trait Traversable<Func>: Functor<Func>
+{
+  /// `traverse :: forall g a b . Applicative g
+  ///   => t a -> (a -> g b) -> g (t b)`
+  fn traverse<'a, 'b, G: 'a, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mapper: BiApp<'b, Func, A, App<'a, G, B>>,
+  ) -> App<'a, G, App<'a, Self, B>>
+  where
+    Self: 'a,
+    G: Applicative<Func>,
+    'a: 'b;
+}
+
 impl<F, G> Functor<FunctionOnceF> for ComposeApp<F, G>
 where
   F: Functor<FunctionOnceF>,
@@ -114,6 +215,156 @@ where
   }
 }
 
+impl<F, G> Functor<SendFunctionOnceF> for ComposeApp<F, G>
+where
+  F: Functor<SendFunctionOnceF>,
+  G: Functor<SendFunctionOnceF>,
+{
+  fn fmap<'a, 'b, A: 'a, B: 'a>(
+    xs1: App<'a, ComposeApp<F, G>, A>,
+    f1: BiApp<'b, SendFunctionOnceF, A, B>,
+  ) -> App<'a, ComposeApp<F, G>, B>
+  where
+    Self: 'a,
+    F: 'a,
+    G: 'a,
+    'a: 'b,
+  {
+    let xs2 = xs1.get_applied();
+    let f2 = f1.get_applied_box();
+    let g = wrap_send_function_once(move |ga| {
+      G::fmap(ga, wrap_send_function_once(f2))
+    });
+    wrap_app(F::fmap(xs2, g))
+  }
+}
+
+impl<F, G> Functor<SendFunctionF> for ComposeApp<F, G>
+where
+  F: Functor<SendFunctionF>,
+  G: Functor<SendFunctionF>,
+{
+  fn fmap<'a, 'b, A: 'a, B: 'a>(
+    fga1: App<'a, ComposeApp<F, G>, A>,
+    mapper1: BiApp<'b, SendFunctionF, A, B>,
+  ) -> App<'a, ComposeApp<F, G>, B>
+  where
+    Self: 'a,
+    F: 'a,
+    G: 'a,
+    'a: 'b,
+  {
+    let fga2 = fga1.get_applied();
+    let mapper2 = mapper1.get_applied_borrow();
+    let mapper4 = wrap_send_function(|ga| {
+      G::fmap(ga, wrap_send_function(mapper2))
+    });
+
+    wrap_app(F::fmap(fga2, mapper4))
+  }
+}
+
+impl<F, G> Functor<SendFunctionMutF> for ComposeApp<F, G>
+where
+  F: Functor<SendFunctionMutF>,
+  G: Functor<SendFunctionMutF>,
+{
+  fn fmap<'a, 'b, A: 'a, B: 'a>(
+    fga1: App<'a, ComposeApp<F, G>, A>,
+    mapper1: BiApp<'b, SendFunctionMutF, A, B>,
+  ) -> App<'a, ComposeApp<F, G>, B>
+  where
+    Self: 'a,
+    F: 'a,
+    G: 'a,
+    'a: 'b,
+  {
+    let fga2 = fga1.get_applied();
+    let mut mapper2 = mapper1.get_applied_box();
+    let mapper4 = wrap_send_function_mut(|ga| {
+      G::fmap(ga, wrap_send_function_mut(&mut mapper2))
+    });
+
+    wrap_app(F::fmap(fga2, mapper4))
+  }
+}
+
+impl<F, G> FunctorMut<FunctionOnceF> for ComposeApp<F, G>
+where
+  F: FunctorMut<FunctionOnceF>,
+  G: FunctorMut<FunctionOnceF>,
+{
+  fn fmap_in_place<'a, 'b, 'c, A: 'a>(
+    fga: &'c mut App<'a, ComposeApp<F, G>, A>,
+    mapper: BiApp<'b, FunctionOnceF, &'c mut A, ()>,
+  ) where
+    Self: 'a,
+    F: 'a,
+    G: 'a,
+    'a: 'b,
+    'c: 'b,
+  {
+    F::fmap_in_place(
+      fga.get_applied_borrow_mut(),
+      wrap_function_once(move |ga| G::fmap_in_place(ga, mapper)),
+    )
+  }
+}
+
+impl<F, G> FunctorMut<FunctionF> for ComposeApp<F, G>
+where
+  F: FunctorMut<FunctionF>,
+  G: FunctorMut<FunctionF>,
+{
+  fn fmap_in_place<'a, 'b, 'c, A: 'a>(
+    fga: &'c mut App<'a, ComposeApp<F, G>, A>,
+    mapper: BiApp<'b, FunctionF, &'c mut A, ()>,
+  ) where
+    Self: 'a,
+    F: 'a,
+    G: 'a,
+    'a: 'b,
+    'c: 'b,
+  {
+    F::fmap_in_place(
+      fga.get_applied_borrow_mut(),
+      wrap_function(|ga| {
+        G::fmap_in_place(
+          ga,
+          wrap_function(|x| FunctionF::apply(&mapper, x)),
+        )
+      }),
+    )
+  }
+}
+
+impl<F, G> FunctorMut<FunctionMutF> for ComposeApp<F, G>
+where
+  F: FunctorMut<FunctionMutF>,
+  G: FunctorMut<FunctionMutF>,
+{
+  fn fmap_in_place<'a, 'b, 'c, A: 'a>(
+    fga: &'c mut App<'a, ComposeApp<F, G>, A>,
+    mut mapper: BiApp<'b, FunctionMutF, &'c mut A, ()>,
+  ) where
+    Self: 'a,
+    F: 'a,
+    G: 'a,
+    'a: 'b,
+    'c: 'b,
+  {
+    F::fmap_in_place(
+      fga.get_applied_borrow_mut(),
+      wrap_function_mut(|ga| {
+        G::fmap_in_place(
+          ga,
+          wrap_function_mut(|x| FunctionMutF::apply_mut(&mut mapper, x)),
+        )
+      }),
+    )
+  }
+}
+
 impl<Func> Functor<Func> for Identity
 where
   Func: IsFnOnce,
@@ -183,6 +434,83 @@ where
   }
 }
 
+/// Map the elements of `xs` with `f`. When `A` and `B` have the same
+/// size and alignment, each mapped element is written over the
+/// element it was mapped from, so that the result reuses the
+/// allocation of `xs`. Otherwise the mapped elements are collected
+/// into a new vector.
+fn map_vec<A, B>(
+  xs: Vec<A>,
+  mut f: impl FnMut(A) -> B,
+) -> Vec<B>
+{
+  if mem::size_of::<A>() != mem::size_of::<B>()
+    || mem::align_of::<A>() != mem::align_of::<B>()
+    || mem::size_of::<A>() == 0
+  {
+    return xs.into_iter().map(f).collect();
+  }
+
+  // Owns the allocation while the elements are being mapped. The
+  // elements before `mapped` have been replaced with values of `B`,
+  // the element at `mapped` has been moved out into `f`, and the
+  // remaining elements are still values of `A`. If `f` panics, the
+  // guard drops each of these and frees the allocation.
+  struct Guard<A, B>
+  {
+    ptr: *mut A,
+    len: usize,
+    cap: usize,
+    mapped: usize,
+    phantom: PhantomData<B>,
+  }
+
+  impl<A, B> Drop for Guard<A, B>
+  {
+    fn drop(&mut self)
+    {
+      unsafe {
+        ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+          self.ptr as *mut B,
+          self.mapped,
+        ));
+        ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+          self.ptr.add(self.mapped + 1),
+          self.len - self.mapped - 1,
+        ));
+        drop(Vec::from_raw_parts(self.ptr, 0, self.cap));
+      }
+    }
+  }
+
+  let mut xs = ManuallyDrop::new(xs);
+
+  let mut guard: Guard<A, B> = Guard {
+    ptr: xs.as_mut_ptr(),
+    len: xs.len(),
+    cap: xs.capacity(),
+    mapped: 0,
+    phantom: PhantomData,
+  };
+
+  // Safety: each element is read exactly once before being
+  // overwritten with its mapped value, and `A` and `B` have the same
+  // layout, so the allocation is valid for `cap` values of `B`.
+  unsafe {
+    while guard.mapped < guard.len {
+      let slot = guard.ptr.add(guard.mapped);
+      let b = f(ptr::read(slot));
+      ptr::write(slot as *mut B, b);
+      guard.mapped += 1;
+    }
+
+    let guard = ManuallyDrop::new(guard);
+    Vec::from_raw_parts(guard.ptr as *mut B, guard.len, guard.cap)
+  }
+}
+
+/// The mapped elements reuse the allocation of the original vector
+/// when `A` and `B` have the same size and alignment.
 impl<Func> Functor<Func> for VecF
 where
   Func: IsFnMut,
@@ -198,8 +526,793 @@ where
     let xs2 = xs1.get_applied();
     let f2 = |x| Func::apply_mut(&mut f1, x);
 
-    let xs3 = xs2.into_iter().map(f2).collect();
+    wrap_app(map_vec(xs2, f2))
+  }
+}
+
+impl<Func> FunctorMut<Func> for Identity
+where
+  Func: IsFnOnce,
+{
+  fn fmap_in_place<'a, 'b, 'c, A: 'a>(
+    fa: &'c mut App<'a, Self, A>,
+    mapper: BiApp<'b, Func, &'c mut A, ()>,
+  ) where
+    Self: 'a,
+    'a: 'b,
+    'c: 'b,
+  {
+    Func::apply_once(mapper, fa.get_applied_borrow_mut())
+  }
+}
+
+impl<Func> FunctorMut<Func> for OptionF
+where
+  Func: IsFnOnce,
+{
+  fn fmap_in_place<'a, 'b, 'c, A: 'a>(
+    fa: &'c mut App<'a, Self, A>,
+    mapper: BiApp<'b, Func, &'c mut A, ()>,
+  ) where
+    Self: 'a,
+    'a: 'b,
+    'c: 'b,
+  {
+    if let Some(a) = fa.get_applied_borrow_mut() {
+      Func::apply_once(mapper, a)
+    }
+  }
+}
+
+impl<Func, E> FunctorMut<Func> for ResultF<E>
+where
+  Func: IsFnOnce,
+{
+  fn fmap_in_place<'a, 'b, 'c, A: 'a>(
+    fa: &'c mut App<'a, Self, A>,
+    mapper: BiApp<'b, Func, &'c mut A, ()>,
+  ) where
+    Self: 'a,
+    'a: 'b,
+    'c: 'b,
+  {
+    if let Ok(a) = fa.get_applied_borrow_mut() {
+      Func::apply_once(mapper, a)
+    }
+  }
+}
+
+impl<Func> FunctorMut<Func> for VecF
+where
+  Func: IsFnMut,
+{
+  fn fmap_in_place<'a, 'b, 'c, A: 'a>(
+    fa: &'c mut App<'a, Self, A>,
+    mut mapper: BiApp<'b, Func, &'c mut A, ()>,
+  ) where
+    Self: 'a,
+    'a: 'b,
+    'c: 'b,
+  {
+    for a in fa.get_applied_borrow_mut() {
+      Func::apply_mut(&mut mapper, a)
+    }
+  }
+}
+
+impl<Func> Applicative<Func> for Identity
+where
+  Func: IsFnOnce,
+{
+  fn pure<'a, A: 'a>(a: A) -> App<'a, Self, A>
+  where
+    Self: 'a,
+  {
+    wrap_app(a)
+  }
+
+  fn apply<'a, A: 'a, B: 'a>(
+    fab: App<'a, Self, BiApp<'a, Func, A, B>>,
+    fa: App<'a, Self, A>,
+  ) -> App<'a, Self, B>
+  where
+    Self: 'a,
+  {
+    wrap_app(Func::apply_once(fab.get_applied(), fa.get_applied()))
+  }
+
+  fn lift_a2<'a, 'b, A: 'a, B: 'a, C: 'a>(
+    fa: App<'a, Self, A>,
+    fb: App<'a, Self, B>,
+    mapper: BiApp<'b, Func, (A, B), C>,
+  ) -> App<'a, Self, C>
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    wrap_app(Func::apply_once(mapper, (fa.get_applied(), fb.get_applied())))
+  }
+}
+
+impl<Func> Applicative<Func> for OptionF
+where
+  Func: IsFnOnce,
+{
+  fn pure<'a, A: 'a>(a: A) -> App<'a, Self, A>
+  where
+    Self: 'a,
+  {
+    wrap_app(Some(a))
+  }
+
+  fn apply<'a, A: 'a, B: 'a>(
+    fab: App<'a, Self, BiApp<'a, Func, A, B>>,
+    fa: App<'a, Self, A>,
+  ) -> App<'a, Self, B>
+  where
+    Self: 'a,
+  {
+    match (fab.get_applied(), fa.get_applied()) {
+      (Some(f), Some(a)) => wrap_app(Some(Func::apply_once(f, a))),
+      _ => wrap_app(None),
+    }
+  }
+
+  fn lift_a2<'a, 'b, A: 'a, B: 'a, C: 'a>(
+    fa: App<'a, Self, A>,
+    fb: App<'a, Self, B>,
+    mapper: BiApp<'b, Func, (A, B), C>,
+  ) -> App<'a, Self, C>
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    match (fa.get_applied(), fb.get_applied()) {
+      (Some(a), Some(b)) => wrap_app(Some(Func::apply_once(mapper, (a, b)))),
+      _ => wrap_app(None),
+    }
+  }
+}
+
+impl<Func, E> Applicative<Func> for ResultF<E>
+where
+  Func: IsFnOnce,
+{
+  fn pure<'a, A: 'a>(a: A) -> App<'a, Self, A>
+  where
+    Self: 'a,
+  {
+    wrap_app(Ok(a))
+  }
+
+  fn apply<'a, A: 'a, B: 'a>(
+    fab: App<'a, Self, BiApp<'a, Func, A, B>>,
+    fa: App<'a, Self, A>,
+  ) -> App<'a, Self, B>
+  where
+    Self: 'a,
+  {
+    match (fab.get_applied(), fa.get_applied()) {
+      (Ok(f), Ok(a)) => wrap_app(Ok(Func::apply_once(f, a))),
+      (Err(err), _) => wrap_app(Err(err)),
+      (_, Err(err)) => wrap_app(Err(err)),
+    }
+  }
+
+  fn lift_a2<'a, 'b, A: 'a, B: 'a, C: 'a>(
+    fa: App<'a, Self, A>,
+    fb: App<'a, Self, B>,
+    mapper: BiApp<'b, Func, (A, B), C>,
+  ) -> App<'a, Self, C>
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    match (fa.get_applied(), fb.get_applied()) {
+      (Ok(a), Ok(b)) => wrap_app(Ok(Func::apply_once(mapper, (a, b)))),
+      (Err(err), _) => wrap_app(Err(err)),
+      (_, Err(err)) => wrap_app(Err(err)),
+    }
+  }
+}
+
+impl<Func> Foldable<Func> for Identity
+where
+  Func: IsFnOnce,
+{
+  fn fold_left<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    init: B,
+    folder: BiApp<'b, Func, (B, A), B>,
+  ) -> B
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    Func::apply_once(folder, (init, fa.get_applied()))
+  }
+}
+
+impl<Func, X> Foldable<Func> for Const<X>
+{
+  fn fold_left<'a, 'b, A: 'a, B: 'a>(
+    _: App<'a, Self, A>,
+    init: B,
+    _: BiApp<'b, Func, (B, A), B>,
+  ) -> B
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    init
+  }
+}
+
+impl<Func> Foldable<Func> for OptionF
+where
+  Func: IsFnOnce,
+{
+  fn fold_left<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    init: B,
+    folder: BiApp<'b, Func, (B, A), B>,
+  ) -> B
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    match fa.get_applied() {
+      Some(a) => Func::apply_once(folder, (init, a)),
+      None => init,
+    }
+  }
+}
+
+impl<Func, E> Foldable<Func> for ResultF<E>
+where
+  Func: IsFnOnce,
+{
+  fn fold_left<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    init: B,
+    folder: BiApp<'b, Func, (B, A), B>,
+  ) -> B
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    match fa.get_applied() {
+      Ok(a) => Func::apply_once(folder, (init, a)),
+      Err(_) => init,
+    }
+  }
+}
+
+impl<Func> Foldable<Func> for VecF
+where
+  Func: IsFnMut,
+{
+  fn fold_left<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    init: B,
+    mut folder: BiApp<'b, Func, (B, A), B>,
+  ) -> B
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    fa.get_applied()
+      .into_iter()
+      .fold(init, |b, a| Func::apply_mut(&mut folder, (b, a)))
+  }
+}
+
+impl<Func> Traversable<Func> for Identity
+where
+  Func: IsFnOnce + WrapFn,
+{
+  fn traverse<'a, 'b, G: 'a, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mapper: BiApp<'b, Func, A, App<'a, G, B>>,
+  ) -> App<'a, G, App<'a, Self, B>>
+  where
+    Self: 'a,
+    G: Applicative<Func>,
+    'a: 'b,
+  {
+    let gb = Func::apply_once(mapper, fa.get_applied());
+    G::fmap(gb, Func::wrap_fn(wrap_app))
+  }
+}
+
+impl<Func, X> Traversable<Func> for Const<X>
+{
+  fn traverse<'a, 'b, G: 'a, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    _: BiApp<'b, Func, A, App<'a, G, B>>,
+  ) -> App<'a, G, App<'a, Self, B>>
+  where
+    Self: 'a,
+    G: Applicative<Func>,
+    'a: 'b,
+  {
+    G::pure(wrap_app(fa.get_applied()))
+  }
+}
+
+impl<Func> Traversable<Func> for OptionF
+where
+  Func: IsFnOnce + WrapFn,
+{
+  fn traverse<'a, 'b, G: 'a, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mapper: BiApp<'b, Func, A, App<'a, G, B>>,
+  ) -> App<'a, G, App<'a, Self, B>>
+  where
+    Self: 'a,
+    G: Applicative<Func>,
+    'a: 'b,
+  {
+    match fa.get_applied() {
+      Some(a) => G::fmap(
+        Func::apply_once(mapper, a),
+        Func::wrap_fn(|b| wrap_app(Some(b))),
+      ),
+      None => G::pure(wrap_app(None)),
+    }
+  }
+}
+
+impl<Func, E> Traversable<Func> for ResultF<E>
+where
+  Func: IsFnOnce + WrapFn,
+{
+  fn traverse<'a, 'b, G: 'a, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mapper: BiApp<'b, Func, A, App<'a, G, B>>,
+  ) -> App<'a, G, App<'a, Self, B>>
+  where
+    Self: 'a,
+    G: Applicative<Func>,
+    'a: 'b,
+  {
+    match fa.get_applied() {
+      Ok(a) => G::fmap(
+        Func::apply_once(mapper, a),
+        Func::wrap_fn(|b| wrap_app(Ok(b))),
+      ),
+      Err(err) => G::pure(wrap_app(Err(err))),
+    }
+  }
+}
+
+impl<Func> Traversable<Func> for VecF
+where
+  Func: IsFnMut + WrapFn,
+{
+  fn traverse<'a, 'b, G: 'a, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mut mapper: BiApp<'b, Func, A, App<'a, G, B>>,
+  ) -> App<'a, G, App<'a, Self, B>>
+  where
+    Self: 'a,
+    G: Applicative<Func>,
+    'a: 'b,
+  {
+    let mut acc = G::pure(Vec::new());
+
+    for a in fa.get_applied() {
+      let gb = Func::apply_mut(&mut mapper, a);
+      acc = G::lift_a2(
+        acc,
+        gb,
+        Func::wrap_fn(|(mut bs, b): (Vec<B>, B)| {
+          bs.push(b);
+          bs
+        }),
+      );
+    }
+
+    G::fmap(acc, Func::wrap_fn(wrap_app))
+  }
+}
+
+impl<Func, const N: usize> Functor<Func> for ArrayF<N>
+where
+  Func: IsFnMut,
+{
+  fn fmap<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mut mapper: BiApp<'b, Func, A, B>,
+  ) -> App<'a, Self, B>
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    wrap_app(fa.get_applied().map(|a| Func::apply_mut(&mut mapper, a)))
+  }
+}
+
+/// Zips two arrays of the same length element-wise.
+impl<Func, const N: usize> Apply<Func> for ArrayF<N>
+where
+  Func: IsFnMut,
+{
+  fn apply<'a, A: 'a, B: 'a>(
+    fab: App<'a, Self, BiApp<'a, Func, A, B>>,
+    fa: App<'a, Self, A>,
+  ) -> App<'a, Self, B>
+  where
+    Self: 'a,
+  {
+    let mut fs = IntoIterator::into_iter(fab.get_applied());
+
+    wrap_app(
+      fa.get_applied()
+        .map(|a| Func::apply_once(fs.next().unwrap(), a)),
+    )
+  }
+
+  fn lift_a2<'a, 'b, A: 'a, B: 'a, C: 'a>(
+    fa: App<'a, Self, A>,
+    fb: App<'a, Self, B>,
+    mut mapper: BiApp<'b, Func, (A, B), C>,
+  ) -> App<'a, Self, C>
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    let mut bs = IntoIterator::into_iter(fb.get_applied());
+
+    wrap_app(
+      fa.get_applied()
+        .map(|a| Func::apply_mut(&mut mapper, (a, bs.next().unwrap()))),
+    )
+  }
+}
+
+/// The zip-with [Apply] of an array of length `N` can only have a
+/// lawful `pure` by replicating the value `N` times, which requires
+/// `A: Clone`. So arrays are not [Applicative], and `pure` is
+/// provided as an inherent function instead.
+impl<const N: usize> ArrayF<N>
+{
+  /// `pure :: forall a . a -> f a`, replicating `a` into each of the
+  /// `N` elements.
+  pub fn pure<'a, A: 'a + Clone>(a: A) -> App<'a, Self, A>
+  {
+    wrap_app(core::array::from_fn(|_| a.clone()))
+  }
+}
+
+impl<Func, const N: usize> Foldable<Func> for ArrayF<N>
+where
+  Func: IsFnMut,
+{
+  fn fold_left<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    init: B,
+    mut folder: BiApp<'b, Func, (B, A), B>,
+  ) -> B
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    IntoIterator::into_iter(fa.get_applied())
+      .fold(init, |b, a| Func::apply_mut(&mut folder, (b, a)))
+  }
+}
+
+impl<Func, const N: usize> Traversable<Func> for ArrayF<N>
+where
+  Func: IsFnMut + WrapFn,
+{
+  fn traverse<'a, 'b, G: 'a, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mapper: BiApp<'b, Func, A, App<'a, G, B>>,
+  ) -> App<'a, G, App<'a, Self, B>>
+  where
+    Self: 'a,
+    G: Applicative<Func>,
+    'a: 'b,
+  {
+    let items = wrap_app(IntoIterator::into_iter(fa.get_applied()).collect());
+    let gbs = VecF::traverse(items, mapper);
+
+    G::fmap(
+      gbs,
+      Func::wrap_fn(|bs: App<'a, VecF, B>| {
+        match <[B; N]>::try_from(bs.get_applied()) {
+          Ok(arr) => wrap_app(arr),
+          Err(_) => unreachable!("traverse preserves the length"),
+        }
+      }),
+    )
+  }
+}
+
+/// `Coyoneda f x = exists b . (f b, b -> x)`, a value `f b` together
+/// with a pending mapper `b -> x`.
+///
+/// `Coyoneda<F, B>` is a [Functor] even when `F` is not. Mapping over
+/// it only composes the new mapper with the pending one, so a chain
+/// of `fmap` calls traverses the underlying `F` only once, when the
+/// value is lowered back with [lower_coyoneda].
+///
+/// The existential base type `B` is a parameter of the type
+/// constructor instead of being hidden in the applied type. Hiding it
+/// would require erasing `f b` and the pending mapper behind a trait
+/// object, but composing a new mapper `x -> y` is generic over `y`,
+/// and lowering needs `fmap` over `F` at `b`, so such a trait could
+/// not be object safe. `B` is fixed by [lift_coyoneda] and inferred
+/// from there, so it rarely needs to be written out.
+///
+/// The composed mapper lives as long as the lifted value, whereas
+/// [Functor::fmap] only lends its mapper for the shorter lifetime
+/// `'b`. So the `Functor` instances are only defined for the function
+/// proxies [FnPtrF], [ClosureF] and [ClosureMutF], whose applied types
+/// can be reboxed at the lifetime of the lifted value. Mappers of
+/// other proxies such as [FunctionF] and [FunctionMutF] are composed
+/// with [map_coyoneda] instead, which requires them to live as long
+/// as the lifted value.
+pub struct Coyoneda<F, B>(PhantomData<F>, PhantomData<B>);
+
+/// The applied type of [Coyoneda].
+pub struct CoyonedaApp<'a, F: 'a, B: 'a, X: 'a>
+{
+  base: App<'a, F, B>,
+  mapper: Box<dyn FnMut(B) -> X + 'a>,
+}
+
+impl<F, B> TypeCon for Coyoneda<F, B> {}
+
+impl<'a, F: 'a, B: 'a, X: 'a> TypeApp<'a, X> for Coyoneda<F, B>
+{
+  type Applied = CoyonedaApp<'a, F, B, X>;
+}
+
+impl<'a, F: 'a, B: 'a, X: 'a> CoyonedaApp<'a, F, B, X>
+{
+  fn map<Y: 'a>(
+    self,
+    mut mapper: impl FnMut(X) -> Y + 'a,
+  ) -> CoyonedaApp<'a, F, B, Y>
+  {
+    let mut pending = self.mapper;
+
+    CoyonedaApp {
+      base: self.base,
+      mapper: Box::new(move |b| mapper(pending(b))),
+    }
+  }
+}
+
+/// Lift `f b` into [Coyoneda] with the identity as pending mapper.
+pub fn lift_coyoneda<'a, F: 'a, B: 'a>(
+  fb: App<'a, F, B>
+) -> App<'a, Coyoneda<F, B>, B>
+{
+  wrap_app(CoyonedaApp {
+    base: fb,
+    mapper: Box::new(|b| b),
+  })
+}
+
+/// Apply the pending mapper of a [Coyoneda] value in a single
+/// `fmap` over `F`.
+pub fn lower_coyoneda<'a, F: 'a, B: 'a, X: 'a>(
+  fx: App<'a, Coyoneda<F, B>, X>
+) -> App<'a, F, X>
+where
+  F: Functor<FunctionMutF>,
+{
+  let CoyonedaApp { base, mapper } = fx.get_applied();
+  F::fmap(base, wrap_function_mut(mapper))
+}
+
+/// `fmap` over [Coyoneda] with a mapper of any [IsFnMut] function
+/// proxy that lives as long as the lifted value.
+///
+/// [FunctionOnceF] mappers are not supported, as the pending mapper
+/// is called once for each element when lowering.
+pub fn map_coyoneda<'a, Func: 'a, F: 'a, B: 'a, X: 'a, Y: 'a>(
+  fx: App<'a, Coyoneda<F, B>, X>,
+  mut mapper: BiApp<'a, Func, X, Y>,
+) -> App<'a, Coyoneda<F, B>, Y>
+where
+  Func: IsFnMut,
+{
+  wrap_app(fx.get_applied().map(move |x| Func::apply_mut(&mut mapper, x)))
+}
+
+impl<F, X> Functor<FnPtrF> for Coyoneda<F, X>
+{
+  fn fmap<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mapper: BiApp<'b, FnPtrF, A, B>,
+  ) -> App<'a, Self, B>
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    let mapper: fn(A) -> B = *mapper.get_applied_borrow();
+    wrap_app(fa.get_applied().map(mapper))
+  }
+}
+
+impl<F, X, C: 'static> Functor<ClosureF<C>> for Coyoneda<F, X>
+{
+  fn fmap<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mapper: BiApp<'b, ClosureF<C>, A, B>,
+  ) -> App<'a, Self, B>
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    let mapper: BiApp<'a, ClosureF<C>, A, B> =
+      wrap_bi_app(*mapper.get_applied_box());
+
+    wrap_app(fa.get_applied().map(move |a| ClosureF::apply(&mapper, a)))
+  }
+}
+
+impl<F, X, C: 'static> Functor<ClosureMutF<C>> for Coyoneda<F, X>
+{
+  fn fmap<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mapper: BiApp<'b, ClosureMutF<C>, A, B>,
+  ) -> App<'a, Self, B>
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    let mut mapper: BiApp<'a, ClosureMutF<C>, A, B> =
+      wrap_bi_app(*mapper.get_applied_box());
+
+    wrap_app(
+      fa.get_applied()
+        .map(move |a| ClosureMutF::apply_mut(&mut mapper, a)),
+    )
+  }
+}
+
+/// `Yoneda f x = forall b . (x -> b) -> f b`, a continuation that
+/// maps a value `f x` with whichever mapper it is given.
+///
+/// `Yoneda<F, B>` is a [Functor] even when `F` is not. Mapping over
+/// it only composes the new mapper with the one the continuation
+/// will eventually be given, so a chain of `fmap` calls traverses the
+/// underlying `F` only once, when the value is lowered back with
+/// [lower_yoneda]. Unlike [Coyoneda], it is lifting with
+/// [lift_yoneda] that requires `F` to be a [Functor], while lowering
+/// does not.
+///
+/// The continuation cannot be polymorphic in `b`, as it is stored as
+/// a trait object, and a trait object cannot have a generic method.
+/// Instead, the result type `B` that the value is eventually lowered
+/// to is a parameter of the type constructor.
+///
+/// As with [Coyoneda], the `Functor` instances are only defined for
+/// the function proxies [FnPtrF], [ClosureF] and [ClosureMutF], and
+/// mappers of other proxies are composed with [map_yoneda].
+pub struct Yoneda<F, B>(PhantomData<F>, PhantomData<B>);
+
+type YonedaCont<'a, F, B, X> =
+  Box<dyn FnOnce(Box<dyn FnMut(X) -> B + 'a>) -> App<'a, F, B> + 'a>;
+
+/// The applied type of [Yoneda].
+pub struct YonedaApp<'a, F: 'a, B: 'a, X: 'a>
+{
+  run: YonedaCont<'a, F, B, X>,
+}
+
+impl<F, B> TypeCon for Yoneda<F, B> {}
+
+impl<'a, F: 'a, B: 'a, X: 'a> TypeApp<'a, X> for Yoneda<F, B>
+{
+  type Applied = YonedaApp<'a, F, B, X>;
+}
+
+impl<'a, F: 'a, B: 'a, X: 'a> YonedaApp<'a, F, B, X>
+{
+  fn map<Y: 'a>(
+    self,
+    mut mapper: impl FnMut(X) -> Y + 'a,
+  ) -> YonedaApp<'a, F, B, Y>
+  {
+    let run = self.run;
+
+    YonedaApp {
+      run: Box::new(move |mut k: Box<dyn FnMut(Y) -> B + 'a>| {
+        run(Box::new(move |x| k(mapper(x))))
+      }),
+    }
+  }
+}
+
+/// Lift `f x` into [Yoneda], deferring the `fmap` over `F` until the
+/// value is lowered.
+pub fn lift_yoneda<'a, F: 'a, B: 'a, X: 'a>(
+  fx: App<'a, F, X>
+) -> App<'a, Yoneda<F, B>, X>
+where
+  F: Functor<FunctionMutF>,
+{
+  wrap_app(YonedaApp {
+    run: Box::new(move |k| F::fmap(fx, wrap_function_mut(k))),
+  })
+}
+
+/// Run the continuation of a [Yoneda] value with the identity,
+/// applying all mappers in a single `fmap` over `F`.
+pub fn lower_yoneda<'a, F: 'a, B: 'a>(
+  fb: App<'a, Yoneda<F, B>, B>
+) -> App<'a, F, B>
+{
+  (fb.get_applied().run)(Box::new(|b| b))
+}
+
+/// `fmap` over [Yoneda] with a mapper of any [IsFnMut] function proxy
+/// that lives as long as the lifted value.
+pub fn map_yoneda<'a, Func: 'a, F: 'a, B: 'a, X: 'a, Y: 'a>(
+  fx: App<'a, Yoneda<F, B>, X>,
+  mut mapper: BiApp<'a, Func, X, Y>,
+) -> App<'a, Yoneda<F, B>, Y>
+where
+  Func: IsFnMut,
+{
+  wrap_app(fx.get_applied().map(move |x| Func::apply_mut(&mut mapper, x)))
+}
+
+impl<F, R> Functor<FnPtrF> for Yoneda<F, R>
+{
+  fn fmap<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mapper: BiApp<'b, FnPtrF, A, B>,
+  ) -> App<'a, Self, B>
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    let mapper: fn(A) -> B = *mapper.get_applied_borrow();
+    wrap_app(fa.get_applied().map(mapper))
+  }
+}
+
+impl<F, R, C: 'static> Functor<ClosureF<C>> for Yoneda<F, R>
+{
+  fn fmap<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mapper: BiApp<'b, ClosureF<C>, A, B>,
+  ) -> App<'a, Self, B>
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    let mapper: BiApp<'a, ClosureF<C>, A, B> =
+      wrap_bi_app(*mapper.get_applied_box());
+
+    wrap_app(fa.get_applied().map(move |a| ClosureF::apply(&mapper, a)))
+  }
+}
+
+impl<F, R, C: 'static> Functor<ClosureMutF<C>> for Yoneda<F, R>
+{
+  fn fmap<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mapper: BiApp<'b, ClosureMutF<C>, A, B>,
+  ) -> App<'a, Self, B>
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    let mut mapper: BiApp<'a, ClosureMutF<C>, A, B> =
+      wrap_bi_app(*mapper.get_applied_box());
 
-    wrap_app(xs3)
+    wrap_app(
+      fa.get_applied()
+        .map(move |a| ClosureMutF::apply_mut(&mut mapper, a)),
+    )
   }
 }
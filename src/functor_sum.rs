@@ -0,0 +1,378 @@
+//! Coproducts and products of functors, for composing instruction
+//! sets of DSLs _à la carte_.
+//!
+//! `App<FunctorSum<F, G>, X>` is either an `App<F, X>` or an
+//! `App<G, X>`, represented as a [Union] of the two. Larger sums are
+//! formed by nesting, e.g. `FunctorSum<F, FunctorSum<G, H>>`.
+//! The [Inject] trait finds the position of a functor `F` inside
+//! such a chain using a type-level index [Nat], so that we can
+//! [inject] an `App<F, X>` into the sum or [prj] it back out without
+//! knowing its position.
+//!
+//! `App<FunctorProduct<F, G>, X>` contains both an `App<F, X>` and an
+//! `App<G, X>`, represented as a [Cons] of the two.
+
+use core::marker::PhantomData;
+
+use crate::{
+  bi_type_app::*,
+  function::*,
+  functor::*,
+  nat::*,
+  product::*,
+  sum::*,
+  type_app::*,
+};
+
+/// `App<FunctorSum<F, G>, X> ~ Union<App<F, X>, App<G, X>>`
+pub struct FunctorSum<F: ?Sized, G: ?Sized>(PhantomData<F>, PhantomData<G>);
+
+/// `App<FunctorProduct<F, G>, X> ~ Cons<App<F, X>, App<G, X>>`
+pub struct FunctorProduct<F: ?Sized, G: ?Sized>(
+  PhantomData<F>,
+  PhantomData<G>,
+);
+
+impl<F: ?Sized, G: ?Sized> TypeCon for FunctorSum<F, G> {}
+
+impl<'a, F: 'a + ?Sized, G: 'a + ?Sized, X: 'a + ?Sized> TypeApp<'a, X>
+  for FunctorSum<F, G>
+{
+  type Applied = Union<App<'a, F, X>, App<'a, G, X>>;
+}
+
+impl<F: ?Sized, G: ?Sized> TypeCon for FunctorProduct<F, G> {}
+
+impl<'a, F: 'a + ?Sized, G: 'a + ?Sized, X: 'a + ?Sized> TypeApp<'a, X>
+  for FunctorProduct<F, G>
+{
+  type Applied = Cons<App<'a, F, X>, App<'a, G, X>>;
+}
+
+impl<Func, F, G> Functor<Func> for FunctorSum<F, G>
+where
+  F: Functor<Func>,
+  G: Functor<Func>,
+{
+  fn fmap<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mapper: BiApp<'b, Func, A, B>,
+  ) -> App<'a, Self, B>
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    match fa.get_applied() {
+      Inl(fx) => wrap_app(Inl(F::fmap(fx, mapper))),
+      Inr(gx) => wrap_app(Inr(G::fmap(gx, mapper))),
+    }
+  }
+}
+
+impl<Func, F, G> Foldable<Func> for FunctorSum<F, G>
+where
+  F: Foldable<Func>,
+  G: Foldable<Func>,
+{
+  fn fold_left<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    init: B,
+    folder: BiApp<'b, Func, (B, A), B>,
+  ) -> B
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    match fa.get_applied() {
+      Inl(fx) => F::fold_left(fx, init, folder),
+      Inr(gx) => G::fold_left(gx, init, folder),
+    }
+  }
+}
+
+impl<Func, F, G> Traversable<Func> for FunctorSum<F, G>
+where
+  Func: WrapFn,
+  F: Traversable<Func>,
+  G: Traversable<Func>,
+{
+  fn traverse<'a, 'b, H: 'a, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mapper: BiApp<'b, Func, A, App<'a, H, B>>,
+  ) -> App<'a, H, App<'a, Self, B>>
+  where
+    Self: 'a,
+    H: Applicative<Func>,
+    'a: 'b,
+  {
+    match fa.get_applied() {
+      Inl(fx) => H::fmap(
+        F::traverse(fx, mapper),
+        Func::wrap_fn(|fb| wrap_app(Inl(fb))),
+      ),
+      Inr(gx) => H::fmap(
+        G::traverse(gx, mapper),
+        Func::wrap_fn(|gb| wrap_app(Inr(gb))),
+      ),
+    }
+  }
+}
+
+impl<F, G> Functor<FunctionF> for FunctorProduct<F, G>
+where
+  F: Functor<FunctionF>,
+  G: Functor<FunctionF>,
+{
+  fn fmap<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mapper: BiApp<'b, FunctionF, A, B>,
+  ) -> App<'a, Self, B>
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    let Cons(fx, gx) = fa.get_applied();
+    let fy = F::fmap(fx, wrap_function(|x| FunctionF::apply(&mapper, x)));
+    let gy = G::fmap(gx, mapper);
+
+    wrap_app(Cons(fy, gy))
+  }
+}
+
+impl<F, G> Functor<FunctionMutF> for FunctorProduct<F, G>
+where
+  F: Functor<FunctionMutF>,
+  G: Functor<FunctionMutF>,
+{
+  fn fmap<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mut mapper: BiApp<'b, FunctionMutF, A, B>,
+  ) -> App<'a, Self, B>
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    let Cons(fx, gx) = fa.get_applied();
+    let fy = F::fmap(
+      fx,
+      wrap_function_mut(|x| FunctionMutF::apply_mut(&mut mapper, x)),
+    );
+    let gy = G::fmap(gx, mapper);
+
+    wrap_app(Cons(fy, gy))
+  }
+}
+
+impl<F, G> Foldable<FunctionF> for FunctorProduct<F, G>
+where
+  F: Foldable<FunctionF>,
+  G: Foldable<FunctionF>,
+{
+  fn fold_left<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    init: B,
+    folder: BiApp<'b, FunctionF, (B, A), B>,
+  ) -> B
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    let Cons(fx, gx) = fa.get_applied();
+    let acc = F::fold_left(
+      fx,
+      init,
+      wrap_function(|x| FunctionF::apply(&folder, x)),
+    );
+
+    G::fold_left(gx, acc, folder)
+  }
+}
+
+impl<F, G> Foldable<FunctionMutF> for FunctorProduct<F, G>
+where
+  F: Foldable<FunctionMutF>,
+  G: Foldable<FunctionMutF>,
+{
+  fn fold_left<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    init: B,
+    mut folder: BiApp<'b, FunctionMutF, (B, A), B>,
+  ) -> B
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    let Cons(fx, gx) = fa.get_applied();
+    let acc = F::fold_left(
+      fx,
+      init,
+      wrap_function_mut(|x| FunctionMutF::apply_mut(&mut folder, x)),
+    );
+
+    G::fold_left(gx, acc, folder)
+  }
+}
+
+impl<F, G> Traversable<FunctionF> for FunctorProduct<F, G>
+where
+  F: Traversable<FunctionF>,
+  G: Traversable<FunctionF>,
+{
+  fn traverse<'a, 'b, H: 'a, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mapper: BiApp<'b, FunctionF, A, App<'a, H, B>>,
+  ) -> App<'a, H, App<'a, Self, B>>
+  where
+    Self: 'a,
+    H: Applicative<FunctionF>,
+    'a: 'b,
+  {
+    let Cons(fx, gx) = fa.get_applied();
+    let hfy =
+      F::traverse(fx, wrap_function(|x| FunctionF::apply(&mapper, x)));
+    let hgy = G::traverse(gx, mapper);
+
+    H::lift_a2(hfy, hgy, wrap_function(|(fy, gy)| wrap_app(Cons(fy, gy))))
+  }
+}
+
+impl<F, G> Traversable<FunctionMutF> for FunctorProduct<F, G>
+where
+  F: Traversable<FunctionMutF>,
+  G: Traversable<FunctionMutF>,
+{
+  fn traverse<'a, 'b, H: 'a, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mut mapper: BiApp<'b, FunctionMutF, A, App<'a, H, B>>,
+  ) -> App<'a, H, App<'a, Self, B>>
+  where
+    Self: 'a,
+    H: Applicative<FunctionMutF>,
+    'a: 'b,
+  {
+    let Cons(fx, gx) = fa.get_applied();
+    let hfy = F::traverse(
+      fx,
+      wrap_function_mut(|x| FunctionMutF::apply_mut(&mut mapper, x)),
+    );
+    let hgy = G::traverse(gx, mapper);
+
+    H::lift_a2(
+      hfy,
+      hgy,
+      wrap_function_mut(|(fy, gy)| wrap_app(Cons(fy, gy))),
+    )
+  }
+}
+
+/// A functor `Self` that can be found inside the functor `Sup`, at
+/// the position `N` of a chain of nested [FunctorSum].
+///
+/// The index `N` is inferred by Rust, and only exists to make the
+/// trait implementations non-overlapping.
+pub trait Inject<Sup: ?Sized, N: Nat>: TypeCon
+{
+  fn inject<'a, X: 'a>(fx: App<'a, Self, X>) -> App<'a, Sup, X>
+  where
+    Self: 'a,
+    Sup: 'a;
+
+  fn project<'a, X: 'a>(sx: App<'a, Sup, X>) -> Option<App<'a, Self, X>>
+  where
+    Self: 'a,
+    Sup: 'a;
+}
+
+impl<F> Inject<F, Z> for F
+where
+  F: TypeCon,
+{
+  fn inject<'a, X: 'a>(fx: App<'a, F, X>) -> App<'a, F, X>
+  where
+    F: 'a,
+  {
+    fx
+  }
+
+  fn project<'a, X: 'a>(fx: App<'a, F, X>) -> Option<App<'a, F, X>>
+  where
+    F: 'a,
+  {
+    Some(fx)
+  }
+}
+
+impl<F, G> Inject<FunctorSum<F, G>, Z> for F
+where
+  F: TypeCon,
+{
+  fn inject<'a, X: 'a>(fx: App<'a, F, X>) -> App<'a, FunctorSum<F, G>, X>
+  where
+    F: 'a,
+    G: 'a,
+  {
+    wrap_app(Inl(fx))
+  }
+
+  fn project<'a, X: 'a>(
+    sx: App<'a, FunctorSum<F, G>, X>
+  ) -> Option<App<'a, F, X>>
+  where
+    F: 'a,
+    G: 'a,
+  {
+    match sx.get_applied() {
+      Inl(fx) => Some(fx),
+      Inr(_) => None,
+    }
+  }
+}
+
+impl<F, G, H, N: Nat> Inject<FunctorSum<G, H>, S<N>> for F
+where
+  F: Inject<H, N>,
+{
+  fn inject<'a, X: 'a>(fx: App<'a, F, X>) -> App<'a, FunctorSum<G, H>, X>
+  where
+    F: 'a,
+    G: 'a,
+    H: 'a,
+  {
+    wrap_app(Inr(F::inject(fx)))
+  }
+
+  fn project<'a, X: 'a>(
+    sx: App<'a, FunctorSum<G, H>, X>
+  ) -> Option<App<'a, F, X>>
+  where
+    F: 'a,
+    G: 'a,
+    H: 'a,
+  {
+    match sx.get_applied() {
+      Inl(_) => None,
+      Inr(hx) => F::project(hx),
+    }
+  }
+}
+
+/// Inject an `App<F, X>` into a functor `Sup` that contains `F`.
+pub fn inject<'a, F: 'a, Sup: 'a, N: Nat, X: 'a>(
+  fx: App<'a, F, X>
+) -> App<'a, Sup, X>
+where
+  F: Inject<Sup, N>,
+{
+  F::inject(fx)
+}
+
+/// Project an `App<Sup, X>` into `App<F, X>`, if the value is in
+/// the `F` part of `Sup`.
+pub fn prj<'a, F: 'a, Sup: 'a, N: Nat, X: 'a>(
+  sx: App<'a, Sup, X>
+) -> Option<App<'a, F, X>>
+where
+  F: Inject<Sup, N>,
+{
+  F::project(sx)
+}
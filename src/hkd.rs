@@ -0,0 +1,200 @@
+//! Higher kinded data (HKD) for user defined structs.
+//!
+//! A common pattern in functional programming is to define a record
+//! type that is parameterized by a type constructor `F`, with each
+//! field having the type `F X`. For example, a configuration record
+//! `Config<OptionF>` can be used to represent a partially specified
+//! configuration, while `Config<Identity>` represents the final
+//! configuration with all fields present.
+//!
+//! This is exactly what [RowApp](crate::row::RowApp) models for the
+//! anonymous product row [Cons](crate::product::Cons). The
+//! [define_hkd] macro lets us give the same treatment to named
+//! structs:
+//!
+//! ```
+//! # use lambek::{define_hkd, type_app::*};
+//! define_hkd! {
+//!   ConfigRow,
+//!   pub struct Config {
+//!     pub host: String,
+//!     pub port: u16,
+//!   }
+//! }
+//!
+//! let defaults: Config<OptionF> = Config {
+//!   host: wrap_app(Some("localhost".to_string())),
+//!   port: wrap_app(Some(80)),
+//! };
+//!
+//! let user: Config<OptionF> = Config {
+//!   host: wrap_app(None),
+//!   port: wrap_app(Some(8080)),
+//! };
+//!
+//! let config: Config<Identity> = user.merge(defaults).validate().unwrap();
+//!
+//! assert_eq!(config.host.get_applied(), "localhost");
+//! assert_eq!(config.port.get_applied(), 8080);
+//! ```
+//!
+//! Given a proxy name `ConfigRow` and a struct definition, the macro
+//! generates the struct `Config<'a, F>` with each field of type `X`
+//! turned into `App<'a, F, X>`. `ConfigRow` is the row type that
+//! implements [RowCon](crate::row::RowCon),
+//! [RowApp](crate::row::RowApp) and [LiftRow](crate::row::LiftRow),
+//! with `Config<'a, F>` being the result of applying `F` to `ConfigRow`.
+//!
+//! The macro also generates the following methods:
+//!
+//! - `Config<'a, F>::sequence`, which sequences the effects of an
+//!   applicative `F` into `App<'a, F, Config<'a, Identity>>`.
+//!
+//! - `Config<'a, OptionF>::validate`, which returns the complete record
+//!   `Config<'a, Identity>` if all fields are present.
+//!
+//! - `Config<'a, OptionF>::merge`, which merges two partial records
+//!   field-wise, preferring the fields in `self`.
+
+#[macro_export]
+macro_rules! define_hkd {
+  (
+    $row:ident,
+    $( #[$meta:meta] )*
+    $vis:vis struct $name:ident {
+      $(
+        $( #[$field_meta:meta] )*
+        $field_vis:vis $field:ident : $field_type:ty
+      ),+ $(,)?
+    }
+  ) => {
+    $vis enum $row {}
+
+    $( #[$meta] )*
+    $vis struct $name<'a, F: 'a + ?Sized> {
+      $(
+        $( #[$field_meta] )*
+        $field_vis $field: $crate::type_app::App<'a, F, $field_type>,
+      )+
+    }
+
+    impl $crate::row::RowCon for $row {}
+
+    impl<'a, F: 'a> $crate::row::RowApp<'a, F> for $row
+    where
+      F: $crate::type_app::TypeCon,
+    {
+      type Applied = $name<'a, F>;
+    }
+
+    impl $crate::row::RowAppGeneric for $row
+    {
+      fn with_row_app<'a, F: 'a, R: 'a>(
+        cont: impl $crate::row::RowAppGenericCont<'a, Self, F, R>
+      ) -> R
+      where
+        Self: 'a,
+        F: $crate::type_app::TypeAppGeneric,
+      {
+        cont.on_row_app()
+      }
+    }
+
+    impl $crate::row::LiftRow for $row
+    {
+      fn lift<'a, F: 'a, G: 'a, Trans>(
+        trans: &Trans,
+        row: $crate::row::AppRow<'a, Self, F>,
+      ) -> $crate::row::AppRow<'a, Self, G>
+      where
+        Self: 'a,
+        Trans: $crate::poly_fn::PolyFn<F, G>,
+        F: $crate::type_app::TypeCon,
+        G: $crate::type_app::TypeCon,
+      {
+        let $name { $( $field ),+ } = *row.get_applied();
+
+        $crate::row::wrap_row::<$row, G>($name {
+          $(
+            $field: trans.call($field),
+          )+
+        })
+      }
+    }
+
+    impl<'a, F: 'a> $name<'a, F>
+    {
+      /// Sequence the effects of `F` in every field, returning the
+      /// complete record inside `F`.
+      #[allow(dead_code)]
+      $vis fn sequence<Func>(
+        self
+      ) -> $crate::type_app::App<'a, F, $name<'a, $crate::type_app::Identity>>
+      where
+        F: $crate::functor::Applicative<Func>,
+        Func: $crate::function::WrapFn,
+      {
+        let $name { $( $field ),+ } = self;
+
+        let acc = F::pure(());
+        $(
+          let acc = F::lift_a2(
+            acc,
+            $field,
+            Func::wrap_fn(|(acc, x)| (acc, x)),
+          );
+        )+
+
+        F::fmap(
+          acc,
+          Func::wrap_fn(|$crate::__hkd_nested_pattern!((); $( $field ),+)| {
+            $name {
+              $( $field: $crate::type_app::wrap_app($field), )+
+            }
+          }),
+        )
+      }
+    }
+
+    impl<'a> $name<'a, $crate::type_app::OptionF>
+    {
+      /// Returns the complete record if all fields are present.
+      #[allow(dead_code)]
+      $vis fn validate(
+        self
+      ) -> Option<$name<'a, $crate::type_app::Identity>>
+      {
+        self.sequence::<$crate::function::FunctionOnceF>().get_applied()
+      }
+
+      /// Merge two partial records field-wise, preferring the fields
+      /// in `self` over the fields in `other`.
+      #[allow(dead_code)]
+      $vis fn merge(
+        self,
+        other: Self,
+      ) -> Self
+      {
+        $name {
+          $(
+            $field: $crate::type_app::wrap_app(
+              self.$field.get_applied().or(other.$field.get_applied())
+            ),
+          )+
+        }
+      }
+    }
+  };
+}
+
+/// Builds the left nested tuple pattern `(((), a), b)` from the field
+/// names `a, b`, matching the accumulator built by `sequence` in
+/// [define_hkd].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hkd_nested_pattern {
+  ( $acc:tt; ) => { $acc };
+  ( $acc:tt; $field:ident $(, $rest:ident )* ) => {
+    $crate::__hkd_nested_pattern!(($acc, $field); $( $rest ),*)
+  };
+}
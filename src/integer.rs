@@ -0,0 +1,139 @@
+//! Type-level signed integers.
+//!
+//! `Pos<N>` is the integer `+N` and `Neg<N>` is the integer `-N`, for
+//! any type-level natural number `N`. Zero is canonically represented
+//! as `Pos<Z>`, which is what the operations here return. A type-level
+//! integer can be reified into an `i64` with [Integer::VALUE].
+//!
+//! ```
+//! # use lambek::{integer::*, nat::*};
+//! type Two = Pos<S<S<Z>>>;
+//! type MinusThree = Neg<S<S<S<Z>>>>;
+//!
+//! assert_eq!(<IntPlus<Two, MinusThree>>::VALUE, -1);
+//! assert_eq!(<IntMinus<Two, MinusThree>>::VALUE, 5);
+//! assert_eq!(<Negated<Two>>::VALUE, -2);
+//! ```
+//!
+//! Addition of integers with different signs subtracts the underlying
+//! natural numbers through [NatDiff], which is implemented for the
+//! Peano encoding only.
+
+use core::marker::PhantomData;
+
+use crate::nat::*;
+
+/// The positive integer `+N`.
+pub struct Pos<N>(PhantomData<N>);
+
+/// The negative integer `-N`.
+pub struct Neg<N>(PhantomData<N>);
+
+pub trait Integer
+{
+  /// The reified value of the integer as an `i64`.
+  const VALUE: i64;
+}
+
+/// Type-level negation, `-Self`.
+pub trait Negate: Integer
+{
+  type Output: Integer;
+}
+
+/// Type-level integer addition, `Self + M`.
+pub trait AddInt<M: Integer>: Integer
+{
+  type Output: Integer;
+}
+
+/// The signed difference `Self - M` of two natural numbers.
+pub trait NatDiff<M: Nat>: Nat
+{
+  type Output: Integer;
+}
+
+pub type Negated<N> = <N as Negate>::Output;
+pub type IntPlus<N, M> = <N as AddInt<M>>::Output;
+pub type IntMinus<N, M> = IntPlus<N, Negated<M>>;
+pub type Difference<N, M> = <N as NatDiff<M>>::Output;
+
+impl<N: Nat> Integer for Pos<N>
+{
+  const VALUE: i64 = N::VALUE as i64;
+}
+
+impl<N: Nat> Integer for Neg<N>
+{
+  const VALUE: i64 = -(N::VALUE as i64);
+}
+
+impl Negate for Pos<Z>
+{
+  type Output = Pos<Z>;
+}
+
+impl<N: Nat> Negate for Pos<S<N>>
+{
+  type Output = Neg<S<N>>;
+}
+
+impl Negate for Pos<U0>
+{
+  type Output = Pos<U0>;
+}
+
+impl<N: Nat> Negate for Pos<B0<N>>
+{
+  type Output = Neg<B0<N>>;
+}
+
+impl<N: Nat> Negate for Pos<B1<N>>
+{
+  type Output = Neg<B1<N>>;
+}
+
+impl<N: Nat> Negate for Neg<N>
+{
+  type Output = Pos<N>;
+}
+
+impl NatDiff<Z> for Z
+{
+  type Output = Pos<Z>;
+}
+
+impl<M: Nat> NatDiff<S<M>> for Z
+{
+  type Output = Neg<S<M>>;
+}
+
+impl<N: Nat> NatDiff<Z> for S<N>
+{
+  type Output = Pos<S<N>>;
+}
+
+impl<N: NatDiff<M>, M: Nat> NatDiff<S<M>> for S<N>
+{
+  type Output = Difference<N, M>;
+}
+
+impl<N: Add<M>, M: Nat> AddInt<Pos<M>> for Pos<N>
+{
+  type Output = Pos<Plus<N, M>>;
+}
+
+impl<N: Add<M>, M: Nat> AddInt<Neg<M>> for Neg<N>
+{
+  type Output = Neg<Plus<N, M>>;
+}
+
+impl<N: NatDiff<M>, M: Nat> AddInt<Neg<M>> for Pos<N>
+{
+  type Output = Difference<N, M>;
+}
+
+impl<N: Nat, M: NatDiff<N>> AddInt<Pos<M>> for Neg<N>
+{
+  type Output = Difference<M, N>;
+}
@@ -0,0 +1,288 @@
+//! Labelled fields for extensible records and variants.
+//!
+//! The product row [Cons] and the sum row [Union] are positional, i.e.
+//! fields are identified by their position within the row. For large
+//! records this is error prone. This module pairs each field with a
+//! type-level label, so that fields can be looked up by name instead.
+//!
+//! A label is a zero-sized marker type, typically defined with the
+//! [label](crate::label!) macro. A field of type `X` with the label
+//! `Label` is represented as [`Field<Label, X>`](Field). A labelled
+//! record is then a product row of such fields:
+//!
+//! ```
+//! # use lambek::{label, record, label::*, product::*};
+//! label!(name, age);
+//!
+//! let person = record! {
+//!   name: "Alice".to_string(),
+//!   age: 42,
+//! };
+//!
+//! let _: &Cons<Field<name, String>, Cons<Field<age, u32>, Top>> = &person;
+//!
+//! assert_eq!(person.get::<name, _, _>(), "Alice");
+//! assert_eq!(*person.get::<age, _, _>(), 42);
+//! ```
+//!
+//! The lookup is done by [HasField], with the position of the field
+//! determined by a type-level index [Nat](crate::nat::Nat) which is
+//! inferred by Rust. Similarly, a labelled variant is a sum row of
+//! fields, and values can be injected into a variant by label using
+//! the [variant](crate::variant!) macro:
+//!
+//! ```
+//! # use lambek::{label, variant, label::*, sum::*};
+//! label!(circle, square);
+//!
+//! type Shape = Union<Field<circle, f64>, Union<Field<square, f64>, Bottom>>;
+//!
+//! let shape: Shape = variant!(square, 2.0);
+//!
+//! assert_eq!(shape.get::<circle, _, _>(), None);
+//! assert_eq!(shape.get::<square, _, _>(), Some(&2.0));
+//! ```
+//!
+//! The [record](crate::record!) macro rejects duplicate labels at
+//! compile time:
+//!
+//! ```compile_fail
+//! # use lambek::{label, record};
+//! label!(name);
+//!
+//! let person = record! {
+//!   name: "Alice",
+//!   name: "Bob",
+//! };
+//! ```
+//!
+//! For rows constructed by other means, a duplicated label results in
+//! the index of the label being ambiguous, and the lookup fails to
+//! compile.
+
+use core::marker::PhantomData;
+
+use crate::{
+  nat::*,
+  product::*,
+  sum::*,
+};
+
+/// A value of type `X` with a type-level label `Label`.
+pub struct Field<Label, X>
+{
+  pub value: X,
+  label: PhantomData<Label>,
+}
+
+impl<Label, X> Field<Label, X>
+{
+  pub fn new(value: X) -> Self
+  {
+    Field {
+      value,
+      label: PhantomData,
+    }
+  }
+}
+
+/// A product row that contains a field of type `X` labelled `Label`,
+/// at the position `N`.
+pub trait HasField<Label, X, N: Nat>
+{
+  fn get_field(&self) -> &X;
+
+  fn get_field_mut(&mut self) -> &mut X;
+
+  fn take_field(self) -> X;
+}
+
+impl<Label, X, Tail> HasField<Label, X, Z> for Cons<Field<Label, X>, Tail>
+{
+  fn get_field(&self) -> &X
+  {
+    &self.0.value
+  }
+
+  fn get_field_mut(&mut self) -> &mut X
+  {
+    &mut self.0.value
+  }
+
+  fn take_field(self) -> X
+  {
+    self.0.value
+  }
+}
+
+impl<Label, X, Y, Tail, N: Nat> HasField<Label, X, S<N>> for Cons<Y, Tail>
+where
+  Tail: HasField<Label, X, N>,
+{
+  fn get_field(&self) -> &X
+  {
+    self.1.get_field()
+  }
+
+  fn get_field_mut(&mut self) -> &mut X
+  {
+    self.1.get_field_mut()
+  }
+
+  fn take_field(self) -> X
+  {
+    self.1.take_field()
+  }
+}
+
+/// A sum row that contains a variant of type `X` labelled `Label`,
+/// at the position `N`.
+pub trait HasVariant<Label, X, N: Nat>: Sized
+{
+  fn inject_variant(x: X) -> Self;
+
+  fn get_variant(&self) -> Option<&X>;
+
+  fn take_variant(self) -> Result<X, Self>;
+}
+
+impl<Label, X, Tail> HasVariant<Label, X, Z> for Union<Field<Label, X>, Tail>
+{
+  fn inject_variant(x: X) -> Self
+  {
+    Inl(Field::new(x))
+  }
+
+  fn get_variant(&self) -> Option<&X>
+  {
+    match self {
+      Inl(field) => Some(&field.value),
+      Inr(_) => None,
+    }
+  }
+
+  fn take_variant(self) -> Result<X, Self>
+  {
+    match self {
+      Inl(field) => Ok(field.value),
+      Inr(tail) => Err(Inr(tail)),
+    }
+  }
+}
+
+impl<Label, X, Y, Tail, N: Nat> HasVariant<Label, X, S<N>> for Union<Y, Tail>
+where
+  Tail: HasVariant<Label, X, N>,
+{
+  fn inject_variant(x: X) -> Self
+  {
+    Inr(Tail::inject_variant(x))
+  }
+
+  fn get_variant(&self) -> Option<&X>
+  {
+    match self {
+      Inl(_) => None,
+      Inr(tail) => tail.get_variant(),
+    }
+  }
+
+  fn take_variant(self) -> Result<X, Self>
+  {
+    match self {
+      Inl(x) => Err(Inl(x)),
+      Inr(tail) => tail.take_variant().map_err(Inr),
+    }
+  }
+}
+
+impl<X, Tail> Cons<X, Tail>
+{
+  /// Get a reference to the field labelled `Label`.
+  pub fn get<Label, T, N: Nat>(&self) -> &T
+  where
+    Self: HasField<Label, T, N>,
+  {
+    self.get_field()
+  }
+
+  /// Get a mutable reference to the field labelled `Label`.
+  pub fn get_mut<Label, T, N: Nat>(&mut self) -> &mut T
+  where
+    Self: HasField<Label, T, N>,
+  {
+    self.get_field_mut()
+  }
+
+  /// Take the field labelled `Label`, discarding the other fields.
+  pub fn take<Label, T, N: Nat>(self) -> T
+  where
+    Self: HasField<Label, T, N>,
+  {
+    self.take_field()
+  }
+}
+
+impl<X, Tail> Union<X, Tail>
+{
+  /// Get a reference to the value if the variant is labelled `Label`.
+  pub fn get<Label, T, N: Nat>(&self) -> Option<&T>
+  where
+    Self: HasVariant<Label, T, N>,
+  {
+    self.get_variant()
+  }
+}
+
+/// Inject a value into a sum row at the variant labelled `Label`.
+pub fn inject_variant<Label, X, N: Nat, Row>(x: X) -> Row
+where
+  Row: HasVariant<Label, X, N>,
+{
+  Row::inject_variant(x)
+}
+
+/// Define zero-sized label types, e.g. `label!(name, age)`.
+#[macro_export]
+macro_rules! label {
+  ( $( $label:ident ),+ $(,)? ) => {
+    $(
+      #[allow(non_camel_case_types, dead_code)]
+      pub enum $label {}
+    )+
+  };
+}
+
+/// Construct a labelled record, e.g. `record! { name: v, age: 3 }`.
+///
+/// Each field name must refer to a label type in scope.
+#[macro_export]
+macro_rules! record {
+  ( @cons ) => { $crate::product::Top };
+  (
+    @cons $label:ident : $value:expr
+    $(, $rest:ident : $rest_value:expr )*
+  ) => {
+    $crate::product::Cons(
+      $crate::label::Field::<$label, _>::new($value),
+      $crate::record!(@cons $( $rest : $rest_value ),*),
+    )
+  };
+  () => { $crate::product::Top };
+  ( $( $label:ident : $value:expr ),+ $(,)? ) => {
+    {
+      #[allow(dead_code, non_snake_case)]
+      struct DuplicateLabels { $( $label: (), )+ }
+
+      $crate::record!(@cons $( $label : $value ),+)
+    }
+  };
+}
+
+/// Inject a value into a labelled variant, e.g. `variant!(circle, 1.0)`.
+#[macro_export]
+macro_rules! variant {
+  ( $label:ty, $value:expr $(,)? ) => {
+    $crate::label::inject_variant::<$label, _, _, _>($value)
+  };
+}
@@ -7,32 +7,56 @@
 //! Learn more about Lambek on the project
 //! [GitHub page](https://github.com/maybevoid/lambek).
 
+#![allow(clippy::multiple_bound_locations)]
+
 #[macro_use]
 pub mod type_app;
 
 /// Traits for the kind of binary type application, `Type -> Type -> Type`.
 pub mod bi_type_app;
 
-/// Traits for constraint kinds, `Type -> Constraint`
 pub mod constraint;
 
-/// The standard `Functor`, `Applicative`, and `Monad` traits.
+/// The standard `Functor`, `Apply`, `Applicative`, `Monad`, `Foldable`
+/// and `Traversable` traits, and the `Yoneda` and `Coyoneda` functors
+/// for fusing chains of `fmap`.
 pub mod functor;
 
+pub mod functor_sum;
+
+pub mod par;
+
 /// Traits for implementing extensible products and variants
 pub mod row;
 
 /// Extensible Variants
 pub mod sum;
 
+pub mod hkd;
+
 /// Extensible Products
 pub mod product;
 
+pub mod packed;
+
+pub mod label;
+
+/// Type-level natural numbers and arithmetic.
 pub mod nat;
 
+pub mod vect;
+
+pub mod boolean;
+
+pub mod integer;
+
+pub mod units;
+
 /// Natural Transformation, `type f ~> g = forall x. f x -> g x`
 pub mod nat_trans;
 
+pub mod poly_fn;
+
 pub mod function;
 
 pub mod reference;
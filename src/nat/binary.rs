@@ -0,0 +1,480 @@
+//! Binary-encoded type-level natural numbers.
+//!
+//! The Peano encoding `S<S<...<Z>>>` grows linearly with the value,
+//! so numbers in the hundreds quickly run into the recursion limit of
+//! the trait solver. The binary encoding grows logarithmically instead:
+//! [U0] is zero, `B0<N>` is `2 * N` and `B1<N>` is `2 * N + 1`, with
+//! the least significant bit outermost. For example, 6 is
+//! `B0<B1<B1<U0>>>`.
+//!
+//! A binary number is canonical when it has no leading zero, i.e. it
+//! never contains `B0<U0>`. The arithmetic defined here always
+//! produces canonical numbers, and [Compare] assumes canonical inputs.
+//!
+//! Binary numbers implement the same [Nat], [Add], [Sub],
+//! [SaturatingSub], [Mul], [Min], [Max] and [Compare] traits as the
+//! Peano numbers. [Peano] and [Binary] convert
+//! between the two encodings, so that binary numbers can be used with
+//! Peano-indexed code such as [Vect](crate::vect::Vect).
+
+use std::marker::PhantomData;
+
+use super::*;
+
+/// Binary zero.
+#[derive(Copy, Clone)]
+pub struct U0;
+
+/// `2 * N`
+#[derive(Copy, Clone)]
+pub struct B0<N>(pub PhantomData<N>);
+
+/// `2 * N + 1`
+#[derive(Copy, Clone)]
+pub struct B1<N>(pub PhantomData<N>);
+
+impl Nat for U0
+{
+  #[allow(non_upper_case_globals)]
+  const Value: U0 = U0;
+
+  const VALUE: usize = 0;
+
+  fn new() -> U0
+  {
+    U0
+  }
+}
+
+impl<N> Nat for B0<N>
+where
+  N: Nat,
+{
+  #[allow(non_upper_case_globals)]
+  const Value: B0<N> = B0(PhantomData);
+
+  const VALUE: usize = N::VALUE * 2;
+
+  fn new() -> B0<N>
+  {
+    B0(PhantomData)
+  }
+}
+
+impl<N> Nat for B1<N>
+where
+  N: Nat,
+{
+  #[allow(non_upper_case_globals)]
+  const Value: B1<N> = B1(PhantomData);
+
+  const VALUE: usize = N::VALUE * 2 + 1;
+
+  fn new() -> B1<N>
+  {
+    B1(PhantomData)
+  }
+}
+
+/// Binary successor, `Self + 1`.
+pub trait Successor: Nat
+{
+  type Output: Nat;
+}
+
+/// Binary predecessor, `Self - 1`, which is not defined for [U0].
+pub trait Predecessor: Nat
+{
+  type Output: Nat;
+}
+
+/// Binary doubling, `2 * Self`, which keeps [U0] canonical.
+pub trait Double: Nat
+{
+  type Output: Nat;
+}
+
+/// Refine a comparison `Self` of the higher bits with the comparison
+/// `Tie` of the lower bits, which is only used when `Self` is [Eq].
+pub trait CompareThen<Tie: Comparison>: Comparison
+{
+  type Output: Comparison;
+}
+
+/// Select the lesser of `N` and `M`, with `Self` being their
+/// [Compare] result.
+pub trait SelectLesser<N: Nat, M: Nat>: Comparison
+{
+  type Output: Nat;
+}
+
+/// Subtract `M` from `N` if it is greater, with `Self` being their
+/// [Compare] result, or give [U0] otherwise.
+pub trait ClampedSub<N: Nat, M: Nat>: Comparison
+{
+  type Output: Nat;
+}
+
+/// Convert a binary number into the Peano encoding.
+pub trait ToPeano: Nat
+{
+  type Output: Nat;
+}
+
+/// Convert a Peano number into the binary encoding.
+pub trait ToBinary: Nat
+{
+  type Output: Nat;
+}
+
+pub type Succ<N> = <N as Successor>::Output;
+pub type Pred<N> = <N as Predecessor>::Output;
+pub type Doubled<N> = <N as Double>::Output;
+pub type ComparedThen<C, Tie> = <C as CompareThen<Tie>>::Output;
+pub type Lesser<C, N, M> = <C as SelectLesser<N, M>>::Output;
+pub type ClampedMinus<C, N, M> = <C as ClampedSub<N, M>>::Output;
+pub type Peano<N> = <N as ToPeano>::Output;
+pub type Binary<N> = <N as ToBinary>::Output;
+
+impl Successor for U0
+{
+  type Output = B1<U0>;
+}
+
+impl<N: Nat> Successor for B0<N>
+{
+  type Output = B1<N>;
+}
+
+impl<N: Successor> Successor for B1<N>
+{
+  type Output = B0<Succ<N>>;
+}
+
+impl<N: Predecessor> Predecessor for B0<N>
+{
+  type Output = B1<Pred<N>>;
+}
+
+impl<N: Double> Predecessor for B1<N>
+{
+  type Output = Doubled<N>;
+}
+
+impl Double for U0
+{
+  type Output = U0;
+}
+
+impl<N: Nat> Double for B0<N>
+{
+  type Output = B0<B0<N>>;
+}
+
+impl<N: Nat> Double for B1<N>
+{
+  type Output = B0<B1<N>>;
+}
+
+impl<Tie: Comparison> CompareThen<Tie> for Lt
+{
+  type Output = Lt;
+}
+
+impl<Tie: Comparison> CompareThen<Tie> for Eq
+{
+  type Output = Tie;
+}
+
+impl<Tie: Comparison> CompareThen<Tie> for Gt
+{
+  type Output = Gt;
+}
+
+impl<N: Nat, M: Nat> SelectLesser<N, M> for Lt
+{
+  type Output = N;
+}
+
+impl<N: Nat, M: Nat> SelectLesser<N, M> for Eq
+{
+  type Output = N;
+}
+
+impl<N: Nat, M: Nat> SelectLesser<N, M> for Gt
+{
+  type Output = M;
+}
+
+impl<N: Nat, M: Nat> ClampedSub<N, M> for Lt
+{
+  type Output = U0;
+}
+
+impl<N: Nat, M: Nat> ClampedSub<N, M> for Eq
+{
+  type Output = U0;
+}
+
+impl<N: Sub<M>, M: Nat> ClampedSub<N, M> for Gt
+{
+  type Output = Minus<N, M>;
+}
+
+impl ToPeano for U0
+{
+  type Output = Z;
+}
+
+impl<N> ToPeano for B0<N>
+where
+  N: ToPeano,
+  Peano<N>: Add<Peano<N>>,
+{
+  type Output = Plus<Peano<N>, Peano<N>>;
+}
+
+impl<N> ToPeano for B1<N>
+where
+  N: ToPeano,
+  Peano<N>: Add<Peano<N>>,
+{
+  type Output = S<Plus<Peano<N>, Peano<N>>>;
+}
+
+impl ToBinary for Z
+{
+  type Output = U0;
+}
+
+impl<N> ToBinary for S<N>
+where
+  N: ToBinary,
+  Binary<N>: Successor,
+{
+  type Output = Succ<Binary<N>>;
+}
+
+impl<M: Nat> Add<M> for U0
+{
+  type Output = M;
+}
+
+impl<N: Nat> Add<U0> for B0<N>
+{
+  type Output = B0<N>;
+}
+
+impl<N: Nat> Add<U0> for B1<N>
+{
+  type Output = B1<N>;
+}
+
+impl<N: Add<M>, M: Nat> Add<B0<M>> for B0<N>
+{
+  type Output = B0<Plus<N, M>>;
+}
+
+impl<N: Add<M>, M: Nat> Add<B1<M>> for B0<N>
+{
+  type Output = B1<Plus<N, M>>;
+}
+
+impl<N: Add<M>, M: Nat> Add<B0<M>> for B1<N>
+{
+  type Output = B1<Plus<N, M>>;
+}
+
+impl<N, M: Nat> Add<B1<M>> for B1<N>
+where
+  N: Add<M>,
+  Plus<N, M>: Successor,
+{
+  type Output = B0<Succ<Plus<N, M>>>;
+}
+
+impl Sub<U0> for U0
+{
+  type Output = U0;
+}
+
+impl<N: Nat> Sub<U0> for B0<N>
+{
+  type Output = B0<N>;
+}
+
+impl<N: Nat> Sub<U0> for B1<N>
+{
+  type Output = B1<N>;
+}
+
+impl<N, M: Nat> Sub<B0<M>> for B0<N>
+where
+  N: Sub<M>,
+  Minus<N, M>: Double,
+{
+  type Output = Doubled<Minus<N, M>>;
+}
+
+impl<N, M: Nat> Sub<B1<M>> for B0<N>
+where
+  N: Sub<M>,
+  Minus<N, M>: Predecessor,
+{
+  type Output = B1<Pred<Minus<N, M>>>;
+}
+
+impl<N, M: Nat> Sub<B0<M>> for B1<N>
+where
+  N: Sub<M>,
+{
+  type Output = B1<Minus<N, M>>;
+}
+
+impl<N, M: Nat> Sub<B1<M>> for B1<N>
+where
+  N: Sub<M>,
+  Minus<N, M>: Double,
+{
+  type Output = Doubled<Minus<N, M>>;
+}
+
+// Saturating subtraction is implemented by the subtrahend, as the
+// blanket `SaturatingSub<Z>` for every `N` rules out implementing it
+// by the minuend for every `M`.
+
+impl<N: Nat> SaturatingSub<U0> for N
+{
+  type Output = N;
+}
+
+impl<N, M: Nat> SaturatingSub<B0<M>> for N
+where
+  N: Compare<B0<M>>,
+  Compared<N, B0<M>>: ClampedSub<N, B0<M>>,
+{
+  type Output = ClampedMinus<Compared<N, B0<M>>, N, B0<M>>;
+}
+
+impl<N, M: Nat> SaturatingSub<B1<M>> for N
+where
+  N: Compare<B1<M>>,
+  Compared<N, B1<M>>: ClampedSub<N, B1<M>>,
+{
+  type Output = ClampedMinus<Compared<N, B1<M>>, N, B1<M>>;
+}
+
+impl<M: Nat> Mul<M> for U0
+{
+  type Output = U0;
+}
+
+impl<N, M: Nat> Mul<M> for B0<N>
+where
+  N: Mul<M>,
+  Times<N, M>: Double,
+{
+  type Output = Doubled<Times<N, M>>;
+}
+
+impl<N, M> Mul<M> for B1<N>
+where
+  N: Mul<M>,
+  M: Add<Doubled<Times<N, M>>>,
+  Times<N, M>: Double,
+{
+  type Output = Plus<M, Doubled<Times<N, M>>>;
+}
+
+impl<M: Nat> Min<M> for U0
+{
+  type Output = U0;
+}
+
+impl<N: Nat, M: Nat> Min<M> for B0<N>
+where
+  B0<N>: Compare<M>,
+  Compared<B0<N>, M>: SelectLesser<B0<N>, M>,
+{
+  type Output = Lesser<Compared<B0<N>, M>, B0<N>, M>;
+}
+
+impl<N: Nat, M: Nat> Min<M> for B1<N>
+where
+  B1<N>: Compare<M>,
+  Compared<B1<N>, M>: SelectLesser<B1<N>, M>,
+{
+  type Output = Lesser<Compared<B1<N>, M>, B1<N>, M>;
+}
+
+impl<M: Nat> Max<M> for U0
+{
+  type Output = M;
+}
+
+impl<N: Nat, M: Nat> Max<M> for B0<N>
+where
+  B0<N>: Compare<M>,
+  Compared<B0<N>, M>: SelectLesser<M, B0<N>>,
+{
+  type Output = Lesser<Compared<B0<N>, M>, M, B0<N>>;
+}
+
+impl<N: Nat, M: Nat> Max<M> for B1<N>
+where
+  B1<N>: Compare<M>,
+  Compared<B1<N>, M>: SelectLesser<M, B1<N>>,
+{
+  type Output = Lesser<Compared<B1<N>, M>, M, B1<N>>;
+}
+
+impl Compare<U0> for U0
+{
+  type Output = Eq;
+}
+
+impl<M: Nat> Compare<B0<M>> for U0
+{
+  type Output = Lt;
+}
+
+impl<M: Nat> Compare<B1<M>> for U0
+{
+  type Output = Lt;
+}
+
+impl<N: Nat> Compare<U0> for B0<N>
+{
+  type Output = Gt;
+}
+
+impl<N: Nat> Compare<U0> for B1<N>
+{
+  type Output = Gt;
+}
+
+impl<N: Compare<M>, M: Nat> Compare<B0<M>> for B0<N>
+{
+  type Output = Compared<N, M>;
+}
+
+impl<N: Compare<M>, M: Nat> Compare<B1<M>> for B1<N>
+{
+  type Output = Compared<N, M>;
+}
+
+impl<N, M: Nat> Compare<B1<M>> for B0<N>
+where
+  N: Compare<M>,
+  Compared<N, M>: CompareThen<Lt>,
+{
+  type Output = ComparedThen<Compared<N, M>, Lt>;
+}
+
+impl<N, M: Nat> Compare<B0<M>> for B1<N>
+where
+  N: Compare<M>,
+  Compared<N, M>: CompareThen<Gt>,
+{
+  type Output = ComparedThen<Compared<N, M>, Gt>;
+}
@@ -0,0 +1,300 @@
+pub mod binary;
+
+use std::{
+  cmp::Ordering,
+  marker::PhantomData,
+};
+
+pub use binary::*;
+
+pub trait Nat
+{
+  #[allow(non_upper_case_globals)]
+  const Value: Self;
+
+  /// The reified value of the natural number as a `usize`.
+  const VALUE: usize;
+
+  fn new() -> Self;
+}
+
+#[derive(Copy, Clone)]
+pub struct Z;
+
+#[derive(Copy, Clone)]
+pub struct S<N>(pub PhantomData<N>);
+
+impl Nat for Z
+{
+  #[allow(non_upper_case_globals)]
+  const Value: Z = Z;
+
+  const VALUE: usize = 0;
+
+  fn new() -> Z
+  {
+    Z
+  }
+}
+
+impl<N> Nat for S<N>
+where
+  N: Nat,
+{
+  #[allow(non_upper_case_globals)]
+  const Value: S<N> = S(PhantomData);
+
+  const VALUE: usize = N::VALUE + 1;
+
+  fn new() -> S<N>
+  {
+    S(PhantomData)
+  }
+}
+
+pub fn succ<N>(_: N) -> S<N>
+{
+  S(PhantomData)
+}
+
+/// Type-level addition, `Self + M`.
+pub trait Add<M: Nat>: Nat
+{
+  type Output: Nat;
+}
+
+/// Type-level checked subtraction, `Self - M`.
+///
+/// `Sub` is only implemented when `M <= Self`, so subtracting a
+/// larger number fails at trait resolution:
+///
+/// ```compile_fail
+/// # use lambek::nat::*;
+/// fn value<N: Nat>() -> usize
+/// {
+///   N::VALUE
+/// }
+///
+/// value::<Minus<S<Z>, S<S<Z>>>>();
+/// ```
+pub trait Sub<M: Nat>: Nat
+{
+  type Output: Nat;
+}
+
+/// Type-level saturating subtraction, `Self - M`, which is `Z` when
+/// `M > Self`.
+pub trait SaturatingSub<M: Nat>: Nat
+{
+  type Output: Nat;
+}
+
+/// Type-level multiplication, `Self * M`.
+pub trait Mul<M: Nat>: Nat
+{
+  type Output: Nat;
+}
+
+/// Type-level minimum of `Self` and `M`.
+pub trait Min<M: Nat>: Nat
+{
+  type Output: Nat;
+}
+
+/// Type-level maximum of `Self` and `M`.
+pub trait Max<M: Nat>: Nat
+{
+  type Output: Nat;
+}
+
+/// Type-level comparison of `Self` with `M`, resulting in one of
+/// [Lt], [Eq] or [Gt].
+pub trait Compare<M: Nat>: Nat
+{
+  type Output: Comparison;
+}
+
+/// The result of a type-level comparison.
+pub trait Comparison
+{
+  const ORDERING: Ordering;
+}
+
+pub enum Lt {}
+pub enum Eq {}
+pub enum Gt {}
+
+pub type Plus<N, M> = <N as Add<M>>::Output;
+pub type Minus<N, M> = <N as Sub<M>>::Output;
+pub type SaturatingMinus<N, M> = <N as SaturatingSub<M>>::Output;
+pub type Times<N, M> = <N as Mul<M>>::Output;
+pub type Minimum<N, M> = <N as Min<M>>::Output;
+pub type Maximum<N, M> = <N as Max<M>>::Output;
+pub type Compared<N, M> = <N as Compare<M>>::Output;
+
+impl Comparison for Lt
+{
+  const ORDERING: Ordering = Ordering::Less;
+}
+
+impl Comparison for Eq
+{
+  const ORDERING: Ordering = Ordering::Equal;
+}
+
+impl Comparison for Gt
+{
+  const ORDERING: Ordering = Ordering::Greater;
+}
+
+impl<M: Nat> Add<M> for Z
+{
+  type Output = M;
+}
+
+impl<N: Nat, M: Nat> Add<M> for S<N>
+where
+  N: Add<M>,
+{
+  type Output = S<Plus<N, M>>;
+}
+
+impl<N: Nat> Sub<Z> for N
+{
+  type Output = N;
+}
+
+impl<N: Nat, M: Nat> Sub<S<M>> for S<N>
+where
+  N: Sub<M>,
+{
+  type Output = Minus<N, M>;
+}
+
+impl<N: Nat> SaturatingSub<Z> for N
+{
+  type Output = N;
+}
+
+impl<M: Nat> SaturatingSub<S<M>> for Z
+{
+  type Output = Z;
+}
+
+impl<N: Nat, M: Nat> SaturatingSub<S<M>> for S<N>
+where
+  N: SaturatingSub<M>,
+{
+  type Output = SaturatingMinus<N, M>;
+}
+
+impl<M: Nat> Mul<M> for Z
+{
+  type Output = Z;
+}
+
+impl<N: Nat, M: Nat> Mul<M> for S<N>
+where
+  N: Mul<M>,
+  M: Add<Times<N, M>>,
+{
+  type Output = Plus<M, Times<N, M>>;
+}
+
+impl<M: Nat> Min<M> for Z
+{
+  type Output = Z;
+}
+
+impl<N: Nat> Min<Z> for S<N>
+{
+  type Output = Z;
+}
+
+impl<N: Nat, M: Nat> Min<S<M>> for S<N>
+where
+  N: Min<M>,
+{
+  type Output = S<Minimum<N, M>>;
+}
+
+impl<M: Nat> Max<M> for Z
+{
+  type Output = M;
+}
+
+impl<N: Nat> Max<Z> for S<N>
+{
+  type Output = S<N>;
+}
+
+impl<N: Nat, M: Nat> Max<S<M>> for S<N>
+where
+  N: Max<M>,
+{
+  type Output = S<Maximum<N, M>>;
+}
+
+impl Compare<Z> for Z
+{
+  type Output = Eq;
+}
+
+impl<M: Nat> Compare<S<M>> for Z
+{
+  type Output = Lt;
+}
+
+impl<N: Nat> Compare<Z> for S<N>
+{
+  type Output = Gt;
+}
+
+impl<N: Nat, M: Nat> Compare<S<M>> for S<N>
+where
+  N: Compare<M>,
+{
+  type Output = Compared<N, M>;
+}
+
+/// A `usize` literal lifted to the type level, for converting const
+/// generic parameters into the corresponding [Nat] through [ToNat].
+pub struct Literal<const N: usize>;
+
+/// Maps a [Literal] to its type-level natural number.
+///
+/// Rust cannot compute a type from a const generic parameter, so
+/// `ToNat` is only implemented for literals up to 64. The opposite
+/// direction is given by [Nat::VALUE] for all natural numbers.
+pub trait ToNat
+{
+  type Nat: Nat;
+}
+
+/// The type-level natural number of the literal `N`, e.g.
+/// `NatOf<2> = S<S<Z>>`.
+pub type NatOf<const N: usize> = <Literal<N> as ToNat>::Nat;
+
+impl ToNat for Literal<0>
+{
+  type Nat = Z;
+}
+
+macro_rules! impl_to_nat {
+  ( $prev:literal; ) => {};
+  ( $prev:literal; $n:literal $( $rest:literal )* ) => {
+    impl ToNat for Literal<$n>
+    {
+      type Nat = S<NatOf<$prev>>;
+    }
+
+    impl_to_nat!($n; $( $rest )*);
+  };
+}
+
+impl_to_nat!(0;
+  1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16
+  17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+  33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48
+  49 50 51 52 53 54 55 56 57 58 59 60 61 62 63 64
+);
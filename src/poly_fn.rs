@@ -0,0 +1,266 @@
+//! Rank-N polymorphic values, `forall x. f x` and `forall x. f x -> g x`.
+//!
+//! Traits such as
+//! [NaturalTransformation](crate::nat_trans::NaturalTransformation)
+//! and [TypeAppCont] encode a `forall X.` callback by having a
+//! trait method that is generic over `X`, with a new trait being
+//! defined for each use. [Forall] and [PolyFn] capture the two most
+//! common shapes once and for all:
+//!
+//! - `V: Forall<F>` is a value of type `forall X. App<F, X>`,
+//!   such as the empty vector.
+//! - `P: PolyFn<F, G>` is a function of type
+//!   `forall X. App<F, X> -> App<G, X>`, such as taking the head
+//!   of a vector.
+//!
+//! Since the methods are generic, the traits are not object safe,
+//! and a rank-N value cannot be erased into a `dyn` trait object.
+//! Instead, a rank-N value is an ordinary value of a concrete type,
+//! which can be stored in a struct field, cloned, or put inside a
+//! [Box], [Rc] or [Arc], all of which implement the traits again.
+//! Two polymorphic functions can be composed with [PolyFn::then].
+//!
+//! The [poly_fn!](crate::poly_fn!) macro defines a new rank-N value
+//! from a generic function body:
+//!
+//! ```
+//! # use lambek::{poly_fn, poly_fn::*, type_app::*};
+//! poly_fn! {
+//!   pub struct Head<X>(xs: App<VecF, X>) -> App<OptionF, X> {
+//!     wrap_app(xs.get_applied().into_iter().next())
+//!   }
+//! }
+//!
+//! poly_fn! {
+//!   pub struct ToVec<X>(x: App<OptionF, X>) -> App<VecF, X> {
+//!     wrap_app(x.get_applied().into_iter().collect())
+//!   }
+//! }
+//!
+//! let first = Head.then(ToVec);
+//!
+//! assert_eq!(first.call(wrap_app(vec![1, 2, 3])).get_applied(), vec![1]);
+//! assert_eq!(first.call(wrap_app(vec!["a"])).get_applied(), vec!["a"]);
+//! ```
+
+use core::marker::PhantomData;
+use std::{
+  rc::Rc,
+  sync::Arc,
+};
+
+use crate::type_app::*;
+
+/// A value of type `forall X. App<F, X>`.
+pub trait Forall<F>
+where
+  F: TypeCon,
+{
+  fn instantiate<'a, X: 'a>(&self) -> App<'a, F, X>
+  where
+    F: 'a;
+}
+
+/// A function of type `forall X. App<F, X> -> App<G, X>`.
+pub trait PolyFn<F, G>
+where
+  F: TypeCon,
+  G: TypeCon,
+{
+  fn call<'a, X: 'a>(
+    &self,
+    fx: App<'a, F, X>,
+  ) -> App<'a, G, X>;
+
+  /// Compose with `next`, calling `self` first.
+  fn then<H, Next>(
+    self,
+    next: Next,
+  ) -> Composed<Self, Next, G>
+  where
+    Self: Sized,
+    H: TypeCon,
+    Next: PolyFn<G, H>,
+  {
+    Composed {
+      first: self,
+      second: next,
+      middle: PhantomData,
+    }
+  }
+}
+
+/// The identity function `forall X. App<F, X> -> App<F, X>`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PolyId;
+
+/// The composition of two polymorphic functions through the
+/// intermediate type constructor `G`, created by [PolyFn::then].
+///
+/// Since the lifetime of the type argument is only known when the
+/// composed function is called, `G` is required to be `'static`.
+pub struct Composed<First, Second, G>
+{
+  first: First,
+  second: Second,
+  middle: PhantomData<G>,
+}
+
+impl<F> PolyFn<F, F> for PolyId
+where
+  F: TypeCon,
+{
+  fn call<'a, X: 'a>(
+    &self,
+    fx: App<'a, F, X>,
+  ) -> App<'a, F, X>
+  {
+    fx
+  }
+}
+
+impl<F, G, H, First, Second> PolyFn<F, H> for Composed<First, Second, G>
+where
+  F: TypeCon,
+  G: TypeCon + 'static,
+  H: TypeCon,
+  First: PolyFn<F, G>,
+  Second: PolyFn<G, H>,
+{
+  fn call<'a, X: 'a>(
+    &self,
+    fx: App<'a, F, X>,
+  ) -> App<'a, H, X>
+  {
+    self.second.call(self.first.call(fx))
+  }
+}
+
+impl<First: Clone, Second: Clone, G> Clone for Composed<First, Second, G>
+{
+  fn clone(&self) -> Self
+  {
+    Composed {
+      first: self.first.clone(),
+      second: self.second.clone(),
+      middle: PhantomData,
+    }
+  }
+}
+
+impl<First: Copy, Second: Copy, G> Copy for Composed<First, Second, G> {}
+
+macro_rules! impl_pointer {
+  ( $( $pointer:ident ),* ) => {
+    $(
+      impl<F, V> Forall<F> for $pointer<V>
+      where
+        F: TypeCon,
+        V: Forall<F> + ?Sized,
+      {
+        fn instantiate<'a, X: 'a>(&self) -> App<'a, F, X>
+        where
+          F: 'a,
+        {
+          V::instantiate(self)
+        }
+      }
+
+      impl<F, G, P> PolyFn<F, G> for $pointer<P>
+      where
+        F: TypeCon,
+        G: TypeCon,
+        P: PolyFn<F, G> + ?Sized,
+      {
+        fn call<'a, X: 'a>(
+          &self,
+          fx: App<'a, F, X>,
+        ) -> App<'a, G, X>
+        {
+          P::call(self, fx)
+        }
+      }
+    )*
+  };
+}
+
+impl_pointer!(Box, Rc, Arc);
+
+impl<F, V> Forall<F> for &V
+where
+  F: TypeCon,
+  V: Forall<F> + ?Sized,
+{
+  fn instantiate<'a, X: 'a>(&self) -> App<'a, F, X>
+  where
+    F: 'a,
+  {
+    V::instantiate(self)
+  }
+}
+
+impl<F, G, P> PolyFn<F, G> for &P
+where
+  F: TypeCon,
+  G: TypeCon,
+  P: PolyFn<F, G> + ?Sized,
+{
+  fn call<'a, X: 'a>(
+    &self,
+    fx: App<'a, F, X>,
+  ) -> App<'a, G, X>
+  {
+    P::call(self, fx)
+  }
+}
+
+/// Define a unit struct implementing [Forall] or [PolyFn] from
+/// a function body that is generic over the type argument.
+///
+/// `pub struct Empty<X>() -> App<VecF, X> { .. }` defines
+/// `Empty: Forall<VecF>`, and
+/// `pub struct Head<X>(xs: App<VecF, X>) -> App<OptionF, X> { .. }`
+/// defines `Head: PolyFn<VecF, OptionF>`.
+#[macro_export]
+macro_rules! poly_fn {
+  (
+    $( #[$attr:meta] )*
+    $vis:vis struct $name:ident < $x:ident > ()
+      -> App < $f:ty, $_x:ident >
+    $body:block
+  ) => {
+    $( #[$attr] )*
+    #[derive(Clone, Copy, Debug, Default)]
+    $vis struct $name;
+
+    impl $crate::poly_fn::Forall<$f> for $name
+    {
+      fn instantiate<'a, $x: 'a>(
+        &self
+      ) -> $crate::type_app::App<'a, $f, $x>
+      where
+        $f: 'a,
+      $body
+    }
+  };
+  (
+    $( #[$attr:meta] )*
+    $vis:vis struct $name:ident < $x:ident >
+      ( $arg:ident : App < $f:ty, $_fx:ident > )
+      -> App < $g:ty, $_gx:ident >
+    $body:block
+  ) => {
+    $( #[$attr] )*
+    #[derive(Clone, Copy, Debug, Default)]
+    $vis struct $name;
+
+    impl $crate::poly_fn::PolyFn<$f, $g> for $name
+    {
+      fn call<'a, $x: 'a>(
+        &self,
+        $arg: $crate::type_app::App<'a, $f, $x>,
+      ) -> $crate::type_app::App<'a, $g, $x>
+      $body
+    }
+  };
+}
@@ -1,5 +1,7 @@
 use crate::{
-  nat_trans::*,
+  function::*,
+  functor::*,
+  poly_fn::*,
   row::*,
   type_app::*,
 };
@@ -8,7 +10,7 @@ pub trait Product {}
 
 pub struct Top;
 
-pub struct Cons<X, Tail>(X, Tail);
+pub struct Cons<X, Tail>(pub X, pub Tail);
 
 impl Product for Top {}
 
@@ -59,46 +61,109 @@ where
   }
 }
 
-impl<Ref> LiftRow<Ref> for Top
-where
-  Ref: TypeCon,
+impl LiftRow for Top
 {
-  fn lift<'a, 'b, F: 'a, G: 'a, Trans>(
-    _: App<'b, Ref, Trans>,
+  fn lift<'a, F: 'a, G: 'a, Trans>(
+    _: &Trans,
     _: AppRow<'a, Self, F>,
   ) -> AppRow<'a, Self, G>
   where
-    'a: 'b,
     Self: 'a,
-    Trans: NaturalTransformation<Ref, F, G>,
-    F: TypeAppGeneric,
-    G: TypeAppGeneric,
+    Trans: PolyFn<F, G>,
+    F: TypeCon,
+    G: TypeCon,
   {
     wrap_row(Top)
   }
 }
 
-impl<Ref, X, Tail> LiftRow<Ref> for Cons<X, Tail>
+impl<X, Tail> LiftRow for Cons<X, Tail>
 where
-  Ref: CloneApp,
-  Tail: LiftRow<Ref>,
+  Tail: LiftRow,
 {
-  fn lift<'a, 'b, F: 'a, G: 'a, Trans>(
-    trans: App<'b, Ref, Trans>,
+  fn lift<'a, F: 'a, G: 'a, Trans>(
+    trans: &Trans,
     row: AppRow<'a, Self, F>,
   ) -> AppRow<'a, Self, G>
   where
-    'a: 'b,
     Self: 'a,
-    Trans: NaturalTransformation<Ref, F, G>,
-    F: TypeAppGeneric,
-    G: TypeAppGeneric,
+    Trans: PolyFn<F, G>,
+    F: TypeCon,
+    G: TypeCon,
   {
     let Cons(fx, tail) = *row.get_applied();
 
-    let gx = Trans::lift(Ref::clone_app(&trans), fx);
+    let gx = trans.call(fx);
     let tail2 = Tail::lift(trans, tail);
 
     wrap_row(Cons(gx, tail2))
   }
 }
+
+impl<Func> SequenceRow<Func> for Top
+{
+  fn sequence<'a, F: 'a>(_: AppRow<'a, Self, F>) -> App<'a, F, Self>
+  where
+    Self: 'a,
+    F: Applicative<Func>,
+  {
+    F::pure(Top)
+  }
+}
+
+impl<Func, X, Tail> SequenceRow<Func> for Cons<X, Tail>
+where
+  Func: WrapFn,
+  Tail: SequenceRow<Func>,
+{
+  fn sequence<'a, F: 'a>(row: AppRow<'a, Self, F>) -> App<'a, F, Self>
+  where
+    Self: 'a,
+    F: Applicative<Func>,
+  {
+    let Cons(fx, tail) = *row.get_applied();
+    let tail2 = Tail::sequence(tail);
+
+    F::lift_a2(fx, tail2, Func::wrap_fn(|(x, tail)| Cons(x, tail)))
+  }
+}
+
+impl<Func> TraverseRow<Func> for Top
+{
+  fn traverse<'a, F: 'a, G: 'a, Trans>(
+    _: &Trans,
+    _: AppRow<'a, Self, F>,
+  ) -> App<'a, G, Self>
+  where
+    Self: 'a,
+    Trans: PolyFn<F, G>,
+    F: TypeCon,
+    G: Applicative<Func>,
+  {
+    G::pure(Top)
+  }
+}
+
+impl<Func, X, Tail> TraverseRow<Func> for Cons<X, Tail>
+where
+  Func: WrapFn,
+  Tail: TraverseRow<Func>,
+{
+  fn traverse<'a, F: 'a, G: 'a, Trans>(
+    trans: &Trans,
+    row: AppRow<'a, Self, F>,
+  ) -> App<'a, G, Self>
+  where
+    Self: 'a,
+    Trans: PolyFn<F, G>,
+    F: TypeCon,
+    G: Applicative<Func>,
+  {
+    let Cons(fx, tail) = *row.get_applied();
+
+    let gx = trans.call(fx);
+    let tail2 = Tail::traverse(trans, tail);
+
+    G::lift_a2(gx, tail2, Func::wrap_fn(|(x, tail)| Cons(x, tail)))
+  }
+}
@@ -69,6 +69,17 @@ impl TypeAppGenericUnsized for Borrow
   }
 }
 
+impl TypeAppGenericSized for Borrow
+{
+  fn with_sized_type_app<'a, X: 'a, R: 'a, Cont: 'a>(cont: Cont) -> R
+  where
+    Self: 'a,
+    Cont: TypeAppContSized<'a, Self, X, R>,
+  {
+    cont.on_sized_type_app()
+  }
+}
+
 impl IsRef for Borrow
 {
   fn get_ref<'a, 'b, X: 'a + ?Sized>(x: &'b App<'a, Self, X>) -> &'b X
@@ -127,6 +138,17 @@ impl TypeAppGenericUnsized for BorrowMut
   }
 }
 
+impl TypeAppGenericSized for BorrowMut
+{
+  fn with_sized_type_app<'a, X: 'a, R: 'a, Cont: 'a>(cont: Cont) -> R
+  where
+    Self: 'a,
+    Cont: TypeAppContSized<'a, Self, X, R>,
+  {
+    cont.on_sized_type_app()
+  }
+}
+
 impl TypeCon for Own {}
 
 impl<'a, X: 'a + ?Sized> TypeApp<'a, X> for Own
@@ -145,6 +167,17 @@ impl TypeAppGenericUnsized for Own
   }
 }
 
+impl TypeAppGenericSized for Own
+{
+  fn with_sized_type_app<'a, X: 'a, R: 'a, Cont: 'a>(cont: Cont) -> R
+  where
+    Self: 'a,
+    Cont: TypeAppContSized<'a, Self, X, R>,
+  {
+    cont.on_sized_type_app()
+  }
+}
+
 impl IsRef for Own
 {
   fn get_ref<'a, 'b, X: 'a + ?Sized>(x: &'b App<'a, Self, X>) -> &'b X
@@ -193,6 +226,17 @@ impl TypeAppGenericUnsized for BoxF
   }
 }
 
+impl TypeAppGenericSized for BoxF
+{
+  fn with_sized_type_app<'a, X: 'a, R: 'a, Cont: 'a>(cont: Cont) -> R
+  where
+    Self: 'a,
+    Cont: TypeAppContSized<'a, Self, X, R>,
+  {
+    cont.on_sized_type_app()
+  }
+}
+
 impl IsRef for BoxF
 {
   fn get_ref<'a, 'b, X: 'a + ?Sized>(x: &'b App<'a, Self, X>) -> &'b X
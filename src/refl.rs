@@ -98,7 +98,10 @@
    ```rust
    use lambek::refl::Refl;
 
-   pub trait ReflVec: Refl {
+   pub trait ReflVec: Refl + Sized
+   where
+       Self::Refl: Sized,
+   {
        fn refl_vec(left: Vec<Self>) -> Vec<Self::Refl>;
    }
 
@@ -120,10 +123,48 @@
    boxed values, so that the conversion can be done at zero cost. `lambek`
    also provides the [`ReflApp`] trait, so that users can easily reflect
    any higher kinded types using the proxy types that implement [`TypeApp`].
+
+   The trait bound `T1: Refl<Refl=T2>` only lives in the type signature
+   of a function, so the proof cannot be stored in a data structure.
+   [`TypeEq<A, B>`](TypeEq) is an ordinary value that can only be
+   constructed when `A` and `B` are the same type, and it can be kept
+   inside enum variants to emulate generalized algebraic data types
+   (GADTs):
+
+   ```rust
+   use lambek::refl::TypeEq;
+
+   enum Expr<T> {
+       Int(TypeEq<T, i64>, i64),
+       IsZero(TypeEq<T, bool>, Box<Expr<i64>>),
+   }
+
+   fn eval<T>(expr: Expr<T>) -> T {
+       match expr {
+           Expr::Int(eq, x) => eq.symm().cast(x),
+           Expr::IsZero(eq, x) => eq.symm().cast(eval(*x) == 0),
+       }
+   }
+
+   let zero = Expr::Int(TypeEq::refl(), 0);
+   assert!(eval(Expr::IsZero(TypeEq::refl(), Box::new(zero))));
+   ```
 */
 
+use core::{
+  any::TypeId,
+  fmt,
+  marker::PhantomData,
+  mem::{
+    self,
+    ManuallyDrop,
+  },
+};
+
 use crate::{
+  bi_type_app::BiTypeApp,
   reference::Borrow,
+  row::RowApp,
   type_app::{
     App,
     AppF,
@@ -131,6 +172,7 @@ use crate::{
     Compose,
     OptionF,
     TypeApp,
+    TypeCon,
   },
 };
 
@@ -240,33 +282,164 @@ where
   refl_transitive_inner::<T1>()
 }
 
-pub fn vec_congruence<T1, T2>() -> impl HasRefl<Vec<T1>, Vec<T2>>
+pub fn refl_trans<W1, W2, T1: ?Sized, T2: ?Sized, T3: ?Sized>(
+  _: W1,
+  _: W2,
+) -> impl HasRefl<T1, T3>
+where
+  W1: HasRefl<T1, T2>,
+  W2: HasRefl<T2, T3>,
+{
+  fn refl_trans_inner<W1, W2, T1: ?Sized, T2: ?Sized, T3: ?Sized>(
+  ) -> impl HasRefl<W1::Left, W2::Right>
+  where
+    W1: HasReflUnbounded<T1, T2, Right = W2::Left>,
+    W2: HasReflUnbounded<T2, T3>,
+  {
+    refl_transitive::<W1::Left, W2::Left, W2::Right>()
+  }
+
+  refl_trans_inner::<W1, W2, T1, T2, T3>()
+}
+
+/**
+   A type-level function `X -> Output`, such as applying a fixed type
+   constructor to `X`. Every type-level function preserves equality,
+   which is proved once and for all by [`congruence`].
+*/
+pub trait TypeFn<X: ?Sized>
+{
+  type Output: ?Sized;
+}
+
+/**
+   `X -> Applied<'a, F, X>`, varying the argument of a type constructor.
+*/
+pub struct AppArg<'a, F: ?Sized>(PhantomData<&'a F>);
+
+/**
+   `F -> Applied<'a, F, X>`, varying the type constructor itself.
+*/
+pub struct AppCon<'a, X: ?Sized>(PhantomData<&'a X>);
+
+/**
+   `X -> <F as BiTypeApp<'a, X, Y>>::Applied`, varying the
+   first argument of a binary type constructor.
+*/
+pub struct BiAppLeft<'a, F: ?Sized, Y: ?Sized>(
+  PhantomData<(&'a F, &'a Y)>,
+);
+
+/**
+   `Y -> <F as BiTypeApp<'a, X, Y>>::Applied`, varying the
+   second argument of a binary type constructor.
+*/
+pub struct BiAppRight<'a, F: ?Sized, X: ?Sized>(
+  PhantomData<(&'a F, &'a X)>,
+);
+
+/**
+   `Row -> <Row as RowApp<'a, F>>::Applied`, varying the row applied to
+   a type constructor.
+*/
+pub struct RowAppArg<'a, F: ?Sized>(PhantomData<&'a F>);
+
+impl<'a, F: ?Sized, X: 'a + ?Sized> TypeFn<X> for AppArg<'a, F>
+where
+  F: TypeApp<'a, X>,
+{
+  type Output = F::Applied;
+}
+
+impl<'a, F: ?Sized, X: 'a + ?Sized> TypeFn<F> for AppCon<'a, X>
+where
+  F: TypeApp<'a, X>,
+{
+  type Output = F::Applied;
+}
+
+impl<'a, F: ?Sized, X: 'a + ?Sized, Y: 'a + ?Sized> TypeFn<X>
+  for BiAppLeft<'a, F, Y>
+where
+  F: BiTypeApp<'a, X, Y>,
+{
+  type Output = F::Applied;
+}
+
+impl<'a, F: ?Sized, X: 'a + ?Sized, Y: 'a + ?Sized> TypeFn<Y>
+  for BiAppRight<'a, F, X>
+where
+  F: BiTypeApp<'a, X, Y>,
+{
+  type Output = F::Applied;
+}
+
+impl<'a, F: 'a + ?Sized, Row: ?Sized> TypeFn<Row> for RowAppArg<'a, F>
+where
+  F: TypeCon,
+  Row: RowApp<'a, F>,
+{
+  type Output = Row::Applied;
+}
+
+/**
+   Applying a type-level function `Fun` to equal types gives equal types.
+*/
+pub fn congruence<Fun: ?Sized, T1: ?Sized, T2: ?Sized>() -> impl HasRefl<
+  <Fun as TypeFn<T1>>::Output,
+  <Fun as TypeFn<T2>>::Output,
+>
 where
   T1: Refl<Refl = T2>,
+  Fun: TypeFn<T1> + TypeFn<T2>,
 {
-  trait VecCongruence: Refl + Sized
+  trait Congruence<Fun: ?Sized>: Refl
   where
-    Self::Refl: Sized,
+    Fun: TypeFn<Self> + TypeFn<Self::Refl>,
   {
-    type Witness: HasRefl<Vec<Self>, Vec<Self::Refl>>;
+    type Witness: HasRefl<
+      <Fun as TypeFn<Self>>::Output,
+      <Fun as TypeFn<Self::Refl>>::Output,
+    >;
+
     fn witness() -> Self::Witness;
   }
 
-  impl<T> VecCongruence for T
+  impl<Fun: ?Sized, T: ?Sized> Congruence<Fun> for T
+  where
+    Fun: TypeFn<T>,
   {
     type Witness = ();
+
     fn witness() -> Self::Witness {}
   }
 
-  fn vec_congruence_inner<T>() -> impl HasRefl<Vec<T>, Vec<T::Refl>>
+  fn congruence_inner<Fun: ?Sized, T: ?Sized>() -> impl HasRefl<
+    <Fun as TypeFn<T>>::Output,
+    <Fun as TypeFn<T::Refl>>::Output,
+  >
   where
-    T: VecCongruence,
-    T::Refl: Sized,
+    T: Congruence<Fun>,
+    Fun: TypeFn<T> + TypeFn<T::Refl>,
   {
     T::witness()
   }
 
-  vec_congruence_inner::<T1>()
+  congruence_inner::<Fun, T1>()
+}
+
+pub fn vec_congruence<T1, T2>() -> impl HasRefl<Vec<T1>, Vec<T2>>
+where
+  T1: Refl<Refl = T2>,
+{
+  struct VecOf;
+
+  impl<X> TypeFn<X> for VecOf
+  {
+    type Output = Vec<X>;
+  }
+
+  congruence::<VecOf, T1, T2>()
 }
 
 pub fn app_congruence<'a, F: ?Sized, T1: 'a + ?Sized, T2: 'a + ?Sized>(
@@ -276,41 +449,108 @@ where
   F: TypeApp<'a, T1>,
   F: TypeApp<'a, T2>,
 {
-  trait AppCongruence<'a, F: ?Sized>: Refl + 'a
-  where
-    F: TypeApp<'a, Self>,
-    F: TypeApp<'a, Self::Refl>,
-  {
-    type Witness: HasRefl<
-      <F as TypeApp<'a, Self>>::Applied,
-      <F as TypeApp<'a, Self::Refl>>::Applied,
-    >;
+  congruence::<AppArg<'a, F>, T1, T2>()
+}
 
-    fn witness() -> Self::Witness;
-  }
+/**
+   Applying equal type constructors to the same type gives equal types.
+*/
+pub fn con_congruence<'a, F1: ?Sized, F2: ?Sized, X: 'a + ?Sized>(
+) -> impl HasRefl<Applied<'a, F1, X>, Applied<'a, F2, X>>
+where
+  F1: Refl<Refl = F2>,
+  F1: TypeApp<'a, X>,
+  F2: TypeApp<'a, X>,
+{
+  congruence::<AppCon<'a, X>, F1, F2>()
+}
+
+pub fn bi_app_congruence_left<'a, F: ?Sized, X1, X2, Y>(
+) -> impl HasRefl<
+  <F as BiTypeApp<'a, X1, Y>>::Applied,
+  <F as BiTypeApp<'a, X2, Y>>::Applied,
+>
+where
+  X1: 'a + ?Sized + Refl<Refl = X2>,
+  X2: 'a + ?Sized,
+  Y: 'a + ?Sized,
+  F: BiTypeApp<'a, X1, Y> + BiTypeApp<'a, X2, Y>,
+{
+  congruence::<BiAppLeft<'a, F, Y>, X1, X2>()
+}
 
-  impl<'a, F: ?Sized, T: 'a + ?Sized> AppCongruence<'a, F> for T
+pub fn bi_app_congruence_right<'a, F: ?Sized, X, Y1, Y2>(
+) -> impl HasRefl<
+  <F as BiTypeApp<'a, X, Y1>>::Applied,
+  <F as BiTypeApp<'a, X, Y2>>::Applied,
+>
+where
+  X: 'a + ?Sized,
+  Y1: 'a + ?Sized + Refl<Refl = Y2>,
+  Y2: 'a + ?Sized,
+  F: BiTypeApp<'a, X, Y1> + BiTypeApp<'a, X, Y2>,
+{
+  congruence::<BiAppRight<'a, F, X>, Y1, Y2>()
+}
+
+/**
+   Applying a type constructor to equal rows gives equal applied rows.
+*/
+pub fn row_congruence<'a, F: 'a + ?Sized, Row1: ?Sized, Row2: ?Sized>(
+) -> impl HasRefl<
+  <Row1 as RowApp<'a, F>>::Applied,
+  <Row2 as RowApp<'a, F>>::Applied,
+>
+where
+  F: TypeCon,
+  Row1: Refl<Refl = Row2> + RowApp<'a, F>,
+  Row2: RowApp<'a, F>,
+{
+  congruence::<RowAppArg<'a, F>, Row1, Row2>()
+}
+
+/**
+   Composing equal type constructors gives equal type constructors.
+*/
+pub fn compose_congruence<'a, F1, F2, G1, G2, X>(
+) -> impl HasRefl<
+  Applied<'a, Compose<F1, G1>, X>,
+  Applied<'a, Compose<F2, G2>, X>,
+>
+where
+  F1: ?Sized + Refl<Refl = F2>,
+  F2: ?Sized,
+  G1: ?Sized + Refl<Refl = G2>,
+  G2: ?Sized,
+  X: 'a + ?Sized,
+  Compose<F1, G1>: TypeApp<'a, X>,
+  Compose<F2, G1>: TypeApp<'a, X>,
+  Compose<F2, G2>: TypeApp<'a, X>,
+{
+  struct ComposeOuter<'a, G: ?Sized, X: ?Sized>(PhantomData<(&'a G, &'a X)>);
+
+  struct ComposeInner<'a, F: ?Sized, X: ?Sized>(PhantomData<(&'a F, &'a X)>);
+
+  impl<'a, F: ?Sized, G: ?Sized, X: 'a + ?Sized> TypeFn<F>
+    for ComposeOuter<'a, G, X>
   where
-    F: TypeApp<'a, Self>,
-    F: TypeApp<'a, Self::Refl>,
+    Compose<F, G>: TypeApp<'a, X>,
   {
-    type Witness = ();
-    fn witness() -> Self::Witness {}
+    type Output = Applied<'a, Compose<F, G>, X>;
   }
 
-  fn app_congruence_inner<'a, F: ?Sized, T: ?Sized>() -> impl HasRefl<
-    <F as TypeApp<'a, T>>::Applied,
-    <F as TypeApp<'a, T::Refl>>::Applied,
-  >
+  impl<'a, F: ?Sized, G: ?Sized, X: 'a + ?Sized> TypeFn<G>
+    for ComposeInner<'a, F, X>
   where
-    T: AppCongruence<'a, F>,
-    F: TypeApp<'a, T>,
-    F: TypeApp<'a, T::Refl>,
+    Compose<F, G>: TypeApp<'a, X>,
   {
-    T::witness()
+    type Output = Applied<'a, Compose<F, G>, X>;
   }
 
-  app_congruence_inner::<'a, F, T1>()
+  refl_trans(
+    congruence::<ComposeOuter<'a, G1, X>, F1, F2>(),
+    congruence::<ComposeInner<'a, F2, X>, G1, G2>(),
+  )
 }
 
 pub fn option_congruence<'a, T1: 'a, T2: 'a>(
@@ -516,3 +756,189 @@ where
 {
   T1::refl_app::<AppF<F>>(xs)
 }
+
+/**
+   A value-level witness that the types `A` and `B` are equal.
+
+   A `TypeEq<A, B>` can only be obtained from [`TypeEq::refl`],
+   [`TypeEq::from_refl`], or by combining existing witnesses. Since the
+   type parameters are invariant, holding a `TypeEq<A, B>` guarantees
+   that `A` and `B` are exactly the same type, including lifetimes.
+
+   ```compile_fail
+   use lambek::refl::TypeEq;
+
+   let eq: TypeEq<u8, u16> = TypeEq::refl();
+   ```
+*/
+pub struct TypeEq<A: ?Sized, B: ?Sized>
+{
+  phantom: PhantomData<(Invariant<A>, Invariant<B>)>,
+}
+
+type Invariant<T> = fn(PhantomData<T>) -> PhantomData<T>;
+
+impl<A: ?Sized> TypeEq<A, A>
+{
+  pub fn refl() -> Self
+  {
+    TypeEq {
+      phantom: PhantomData,
+    }
+  }
+}
+
+impl<A: ?Sized, B: ?Sized> TypeEq<A, B>
+{
+  /**
+     Turn the trait bound `A: Refl<Refl = B>` into a value.
+  */
+  pub fn from_refl() -> Self
+  where
+    A: Refl<Refl = B>,
+  {
+    TypeEq {
+      phantom: PhantomData,
+    }
+  }
+
+  /**
+     Turn a trait witness `W: HasRefl<A, B>` into a value.
+  */
+  pub fn from_witness<W>(_: W) -> Self
+  where
+    W: HasRefl<A, B>,
+  {
+    fn from_witness_inner<W, A: ?Sized, B: ?Sized>(
+    ) -> TypeEq<W::Left, W::Right>
+    where
+      W: HasReflUnbounded<A, B>,
+    {
+      TypeEq::from_refl()
+    }
+
+    from_witness_inner::<W, A, B>()
+  }
+
+  pub fn cast(
+    self,
+    a: A,
+  ) -> B
+  where
+    A: Sized,
+    B: Sized,
+  {
+    let a = ManuallyDrop::new(a);
+
+    // Safety: A and B are the same type.
+    unsafe { mem::transmute_copy::<A, B>(&a) }
+  }
+
+  pub fn cast_ref<'r>(
+    self,
+    a: &'r A,
+  ) -> &'r B
+  {
+    // Safety: A and B are the same type.
+    unsafe { mem::transmute_copy::<&'r A, &'r B>(&a) }
+  }
+
+  pub fn cast_mut<'r>(
+    self,
+    a: &'r mut A,
+  ) -> &'r mut B
+  {
+    // Safety: A and B are the same type, and `a` is not used again.
+    unsafe { mem::transmute_copy::<&'r mut A, &'r mut B>(&a) }
+  }
+
+  pub fn symm(self) -> TypeEq<B, A>
+  {
+    TypeEq {
+      phantom: PhantomData,
+    }
+  }
+
+  pub fn trans<C: ?Sized>(
+    self,
+    _: TypeEq<B, C>,
+  ) -> TypeEq<A, C>
+  {
+    TypeEq {
+      phantom: PhantomData,
+    }
+  }
+
+  /**
+     Congruence: applying equal types to the same type constructor `F`
+     gives equal types.
+  */
+  pub fn lift<'a, F: ?Sized>(
+    self
+  ) -> TypeEq<Applied<'a, F, A>, Applied<'a, F, B>>
+  where
+    A: 'a,
+    B: 'a,
+    F: TypeApp<'a, A> + TypeApp<'a, B>,
+  {
+    TypeEq {
+      phantom: PhantomData,
+    }
+  }
+}
+
+/**
+   Decide at runtime whether the `'static` types `A` and `B` are equal,
+   by comparing their [`TypeId`]s.
+
+   Unlike [`has_refl`], which needs the equality to be known from the
+   trait bound `A: Refl<Refl = B>`, this can be used when the types
+   only meet at runtime, e.g. at the boundary of a dynamically loaded
+   plugin. A trait witness [`HasRefl`] cannot be produced at runtime,
+   but the returned [`TypeEq`] can be used in all the places where the
+   witness would have been used to cast a value.
+*/
+pub fn try_refl<A: ?Sized + 'static, B: ?Sized + 'static>(
+) -> Option<TypeEq<A, B>>
+{
+  if TypeId::of::<A>() == TypeId::of::<B>() {
+    Some(TypeEq {
+      phantom: PhantomData,
+    })
+  } else {
+    None
+  }
+}
+
+/**
+   Cast `a` into `B` if the two types are equal at runtime, or give
+   back `a` otherwise.
+*/
+pub fn try_cast<A: 'static, B: 'static>(a: A) -> Result<B, A>
+{
+  match try_refl::<A, B>() {
+    Some(eq) => Ok(eq.cast(a)),
+    None => Err(a),
+  }
+}
+
+impl<A: ?Sized, B: ?Sized> Clone for TypeEq<A, B>
+{
+  fn clone(&self) -> Self
+  {
+    *self
+  }
+}
+
+impl<A: ?Sized, B: ?Sized> Copy for TypeEq<A, B> {}
+
+impl<A: ?Sized, B: ?Sized> fmt::Debug for TypeEq<A, B>
+{
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter<'_>,
+  ) -> fmt::Result
+  {
+    f.write_str("TypeEq")
+  }
+}
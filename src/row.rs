@@ -1,5 +1,12 @@
 use crate::{
-  nat_trans::*,
+  functor::*,
+  poly_fn::*,
+  refl::{
+    has_refl,
+    refl_symmetric,
+    HasReflUnbounded,
+    Refl,
+  },
   type_app::*,
 };
 
@@ -31,6 +38,83 @@ pub trait RowAppGenericCont<'a, Row: 'a, F: 'a, R: 'a>
     Row: RowApp<'a, F>;
 }
 
+/// A witness that `Row: RowApp<'a, F>` holds, in the same way as
+/// [TypeAppWitness] for unary type applications.
+pub trait RowAppWitness<'a, Row: 'a, F: 'a>
+where
+  F: TypeCon,
+{
+  type Witness: RowApp<'a, F> + Refl<Refl = Row> + 'a;
+}
+
+impl<'a, Row: 'a, F: 'a> RowAppWitness<'a, Row, F> for Row
+where
+  F: TypeCon,
+  Row: RowApp<'a, F>,
+{
+  type Witness = Self;
+}
+
+/// Recover the `Row: RowApp<'a, F>` constraint from a witness
+/// and use it to run `cont`.
+pub fn with_row_app_witness<'a, W, Cont, Row: 'a, F: 'a, R: 'a>(
+  _: W,
+  cont: Cont,
+) -> R
+where
+  F: TypeCon,
+  W: RowAppWitness<'a, Row, F>,
+  Cont: RowAppGenericCont<'a, Row, F, R>,
+{
+  trait ReflCont<'a, Cont, F: 'a, R: 'a>: Refl
+  where
+    Self::Refl: Sized + 'a,
+  {
+    type ContRefl: RowAppGenericCont<'a, Self::Refl, F, R>;
+
+    fn refl_cont(cont: Cont) -> Self::ContRefl;
+  }
+
+  impl<'a, Cont, Row: 'a, F: 'a, R: 'a> ReflCont<'a, Cont, F, R> for Row
+  where
+    Cont: RowAppGenericCont<'a, Row, F, R>,
+  {
+    type ContRefl = Cont;
+
+    fn refl_cont(cont: Cont) -> Cont
+    {
+      cont
+    }
+  }
+
+  fn inner_1<'a, Cont, W, Row1, Row2, F: 'a, R: 'a>(
+    _: W,
+    cont: Cont,
+  ) -> R
+  where
+    F: TypeCon,
+    W: HasReflUnbounded<Row1, Row2>,
+    W::Left: ReflCont<'a, Cont, F, R> + 'a,
+    W::Right: RowApp<'a, F> + Sized + 'a,
+  {
+    W::Left::refl_cont(cont).on_row_app()
+  }
+
+  fn inner_2<'a, Cont, Row: 'a, Witness: 'a, F: 'a, R: 'a>(cont: Cont) -> R
+  where
+    F: TypeCon,
+    Witness: RowApp<'a, F> + Refl<Refl = Row>,
+    Cont: RowAppGenericCont<'a, Row, F, R>,
+  {
+    inner_1::<'a, Cont, _, Row, Witness, F, R>(
+      refl_symmetric(has_refl::<Witness, Row>()),
+      cont,
+    )
+  }
+
+  inner_2::<'a, Cont, Row, W::Witness, F, R>(cont)
+}
+
 pub trait HasRowApp<'a, Row: 'a + ?Sized, F: 'a + ?Sized + TypeCon>
 {
   fn get_applied(self: Box<Self>) -> Box<Row::Applied>
@@ -48,6 +132,18 @@ pub trait HasRowApp<'a, Row: 'a + ?Sized, F: 'a + ?Sized + TypeCon>
 
 pub type AppRow<'a, Row, F> = Box<dyn HasRowApp<'a, Row, F> + 'a>;
 
+/// A thread safe variant of [AppRow] that can be sent to another
+/// thread, constructed with [wrap_row_send]. Since the fields of a
+/// [Cons](crate::product::Cons) row are wrapped in [App], only rows
+/// that store their fields unwrapped, such as
+/// [Packed](crate::packed::Packed), can be sent.
+pub type SendAppRow<'a, Row, F> = Box<dyn HasRowApp<'a, Row, F> + Send + 'a>;
+
+/// A variant of [AppRow] that is both [Send] and [Sync],
+/// constructed with [wrap_row_sync].
+pub type SyncAppRow<'a, Row, F> =
+  Box<dyn HasRowApp<'a, Row, F> + Send + Sync + 'a>;
+
 pub fn wrap_row<'a, Row: 'a, F: 'a>(row: Row::Applied) -> AppRow<'a, Row, F>
 where
   F: TypeCon,
@@ -56,6 +152,28 @@ where
   Box::new(row)
 }
 
+pub fn wrap_row_send<'a, Row: 'a, F: 'a>(
+  row: Row::Applied
+) -> SendAppRow<'a, Row, F>
+where
+  F: TypeCon,
+  Row: RowApp<'a, F>,
+  Row::Applied: Send,
+{
+  Box::new(row)
+}
+
+pub fn wrap_row_sync<'a, Row: 'a, F: 'a>(
+  row: Row::Applied
+) -> SyncAppRow<'a, Row, F>
+where
+  F: TypeCon,
+  Row: RowApp<'a, F>,
+  Row::Applied: Send + Sync,
+{
+  Box::new(row)
+}
+
 impl<'a, Row: 'a, F: 'a, RF: 'a> HasRowApp<'a, Row, F> for RF
 where
   F: TypeCon,
@@ -77,18 +195,63 @@ where
   }
 }
 
-pub trait LiftRow<Ref>: RowCon
-where
-  Ref: TypeCon,
+/// Apply a polymorphic function `F ~> G` to every field of a row.
+///
+/// `lift :: (forall x . f x -> g x) -> Row f -> Row g`
+pub trait LiftRow: RowCon
 {
-  fn lift<'a, 'b, F: 'a, G: 'a, Trans>(
-    trans: App<'b, Ref, Trans>,
+  fn lift<'a, F: 'a, G: 'a, Trans>(
+    trans: &Trans,
     row: AppRow<'a, Self, F>,
   ) -> AppRow<'a, Self, G>
   where
-    'a: 'b,
     Self: 'a,
-    Trans: NaturalTransformation<Ref, F, G>,
-    F: TypeAppGeneric,
-    G: TypeAppGeneric;
+    Trans: PolyFn<F, G>,
+    F: TypeCon,
+    G: TypeCon;
+}
+
+/// Sequence the effects of every field in a product row.
+///
+/// `sequence :: Row f -> f (Row Identity)`
+///
+/// The result is the applicative `F` applied to the plain row type
+/// itself, e.g. `AppRow<Cons<A, Cons<B, Top>>, OptionF>` becomes
+/// `App<OptionF, Cons<A, Cons<B, Top>>>`.
+pub trait SequenceRow<Func>: RowCon
+{
+  fn sequence<'a, F: 'a>(row: AppRow<'a, Self, F>) -> App<'a, F, Self>
+  where
+    Self: 'a,
+    F: Applicative<Func>;
+}
+
+/// Apply a polymorphic function `F ~> G` to every field of a product
+/// row, and sequence the effects of `G` at the same time.
+///
+/// `traverse :: (forall x . f x -> g x) -> Row f -> g (Row Identity)`
+pub trait TraverseRow<Func>: RowCon
+{
+  fn traverse<'a, F: 'a, G: 'a, Trans>(
+    trans: &Trans,
+    row: AppRow<'a, Self, F>,
+  ) -> App<'a, G, Self>
+  where
+    Self: 'a,
+    Trans: PolyFn<F, G>,
+    F: TypeCon,
+    G: Applicative<Func>;
+}
+
+/// The dual of [SequenceRow] for sum rows. Since only one variant
+/// is present, distributing `F` over a sum only requires `F` to be a
+/// [Functor].
+///
+/// `distribute :: Row f -> f (Row Identity)`
+pub trait Distribute<Func>: RowCon
+{
+  fn distribute<'a, F: 'a>(row: AppRow<'a, Self, F>) -> App<'a, F, Self>
+  where
+    Self: 'a,
+    F: Functor<Func>;
 }
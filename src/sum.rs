@@ -1,5 +1,7 @@
 use crate::{
-  nat_trans::*,
+  function::*,
+  functor::*,
+  poly_fn::*,
   row::*,
   type_app::*,
 };
@@ -68,44 +70,67 @@ where
   }
 }
 
-impl<Ref> LiftRow<Ref> for Bottom
-where
-  Ref: TypeCon,
+impl LiftRow for Bottom
 {
-  fn lift<'a, 'b, F: 'a, G: 'a, Trans>(
-    _: App<'b, Ref, Trans>,
+  fn lift<'a, F: 'a, G: 'a, Trans>(
+    _: &Trans,
     row: AppRow<'a, Self, F>,
   ) -> AppRow<'a, Self, G>
   where
-    'a: 'b,
     Self: 'a,
-    Trans: NaturalTransformation<Ref, F, G>,
-    F: TypeAppGeneric,
-    G: TypeAppGeneric,
+    Trans: PolyFn<F, G>,
+    F: TypeCon,
+    G: TypeCon,
   {
     match *row.get_applied() {}
   }
 }
 
-impl<Ref, X, Tail> LiftRow<Ref> for Union<X, Tail>
+impl<X, Tail> LiftRow for Union<X, Tail>
 where
-  Ref: TypeCon,
-  Tail: LiftRow<Ref>,
+  Tail: LiftRow,
 {
-  fn lift<'a, 'b, F: 'a, G: 'a, Trans>(
-    trans: App<'b, Ref, Trans>,
+  fn lift<'a, F: 'a, G: 'a, Trans>(
+    trans: &Trans,
     row: AppRow<'a, Self, F>,
   ) -> AppRow<'a, Self, G>
   where
-    'a: 'b,
     Self: 'a,
-    Trans: NaturalTransformation<Ref, F, G>,
-    F: TypeAppGeneric,
-    G: TypeAppGeneric,
+    Trans: PolyFn<F, G>,
+    F: TypeCon,
+    G: TypeCon,
   {
     match *row.get_applied() {
-      Inl(fx) => wrap_row(Inl(Trans::lift(trans, fx))),
+      Inl(fx) => wrap_row(Inl(trans.call(fx))),
       Inr(tail) => wrap_row(Inr(Tail::lift(trans, tail))),
     }
   }
 }
+
+impl<Func> Distribute<Func> for Bottom
+{
+  fn distribute<'a, F: 'a>(row: AppRow<'a, Self, F>) -> App<'a, F, Self>
+  where
+    Self: 'a,
+    F: Functor<Func>,
+  {
+    match *row.get_applied() {}
+  }
+}
+
+impl<Func, X, Tail> Distribute<Func> for Union<X, Tail>
+where
+  Func: WrapFn,
+  Tail: Distribute<Func>,
+{
+  fn distribute<'a, F: 'a>(row: AppRow<'a, Self, F>) -> App<'a, F, Self>
+  where
+    Self: 'a,
+    F: Functor<Func>,
+  {
+    match *row.get_applied() {
+      Inl(fx) => F::fmap(fx, Func::wrap_fn(Inl)),
+      Inr(tail) => F::fmap(Tail::distribute(tail), Func::wrap_fn(Inr)),
+    }
+  }
+}
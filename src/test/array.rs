@@ -0,0 +1,76 @@
+use crate::{
+  bi_type_app::*,
+  function::*,
+  functor::*,
+  nat::*,
+  type_app::*,
+  vect::*,
+};
+
+#[test]
+fn test_nat_literal()
+{
+  assert_eq!(<NatOf<0>>::VALUE, 0);
+  assert_eq!(<NatOf<3>>::VALUE, 3);
+  assert_eq!(<NatOf<64>>::VALUE, 64);
+
+  let xs: Vect<S<S<S<Z>>>, u32> = Vect::from_array([1, 2, 3]);
+  assert_eq!(xs.len(), 3);
+
+  let arr: [u32; 3] = xs.into_array();
+  assert_eq!(arr, [1, 2, 3]);
+}
+
+#[test]
+fn test_array_functor()
+{
+  let xs: App<ArrayF<3>, u32> = wrap_app([1, 2, 3]);
+  let ys = ArrayF::fmap(xs, wrap_function(|x: u32| x * 10));
+  assert_eq!(ys.get_applied(), [10, 20, 30]);
+
+  let xs: App<ArrayF<3>, u32> = wrap_app([1, 2, 3]);
+  let ys: App<ArrayF<3>, &str> = wrap_app(["a", "b", "c"]);
+  let zs = ArrayF::lift_a2(
+    xs,
+    ys,
+    wrap_function_mut(|(x, y): (u32, &str)| format!("{}{}", y, x)),
+  );
+  assert_eq!(zs.get_applied(), ["a1", "b2", "c3"]);
+
+  let fs: App<ArrayF<2>, BiApp<FunctionMutF, u32, u32>> = wrap_app([
+    wrap_function_mut(|x: u32| x + 1),
+    wrap_function_mut(|x: u32| x * 2),
+  ]);
+  let ys = ArrayF::apply(fs, wrap_app([10, 20]));
+  assert_eq!(ys.get_applied(), [11, 40]);
+
+  let one: App<ArrayF<1>, u32> = ArrayF::pure(7);
+  assert_eq!(one.get_applied(), [7]);
+
+  let three: App<ArrayF<3>, String> = ArrayF::pure("a".to_string());
+  assert_eq!(three.get_applied(), ["a", "a", "a"]);
+
+  let xs: App<ArrayF<3>, u32> = wrap_app([1, 2, 3]);
+  let total = ArrayF::fold_left(xs, 0, wrap_function_mut(|(b, a)| b + a));
+  assert_eq!(total, 6);
+}
+
+#[test]
+fn test_array_traverse()
+{
+  let xs: App<ArrayF<3>, u32> = wrap_app([1, 2, 3]);
+  let res: Option<[u32; 3]> = ArrayF::traverse::<OptionF, _, _>(
+    xs,
+    wrap_function_mut(|x: u32| wrap_app(x.checked_sub(1))),
+  )
+  .get_applied()
+  .map(App::get_applied);
+  assert_eq!(res, Some([0, 1, 2]));
+
+  let xs: App<ArrayF<3>, u32> = wrap_app([1, 0, 3]);
+  let res = ArrayF::traverse::<OptionF, _, _>(
+    xs,
+    wrap_function_mut(|x: u32| wrap_app(x.checked_sub(1))),
+  );
+  assert!(res.get_applied().is_none());
+}
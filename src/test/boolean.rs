@@ -0,0 +1,35 @@
+use crate::{
+  boolean::*,
+  test::assert_same,
+};
+
+fn value<B: Bool>() -> bool
+{
+  B::VALUE
+}
+
+#[test]
+fn test_bool_operations()
+{
+  assert_same::<Not<True>, False>();
+  assert_same::<Not<Not<True>>, True>();
+
+  assert_same::<And<True, True>, True>();
+  assert_same::<And<True, False>, False>();
+  assert_same::<And<False, True>, False>();
+
+  assert_same::<Or<False, False>, False>();
+  assert_same::<Or<False, True>, True>();
+  assert_same::<Or<True, False>, True>();
+
+  assert!(value::<True>());
+  assert!(!value::<And<True, Not<True>>>());
+}
+
+#[test]
+fn test_bool_conditional()
+{
+  assert_same::<If<True, u32, String>, u32>();
+  assert_same::<If<Not<True>, u32, String>, String>();
+  assert_same::<If<False, (), [u8; 4]>, [u8; 4]>();
+}
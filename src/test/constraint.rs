@@ -3,6 +3,7 @@ use std::fmt::Display;
 use crate::{
   bi_type_app::*,
   constraint::*,
+  define_constraint,
 };
 
 enum DisplayConstraint {}
@@ -116,3 +117,219 @@ fn test_display_constraint()
 
   use_display(&"Hello World".to_string());
 }
+
+trait Greet
+{
+  fn greet(&self) -> String;
+}
+
+impl Greet for u32
+{
+  fn greet(&self) -> String
+  {
+    format!("hello {}", self)
+  }
+}
+
+define_constraint!(GreetConstraint, GreetCont, on_greet, Greet);
+
+struct GreetWith<'a, X>(&'a X);
+
+impl<'a, X> GreetCont<X, String> for GreetWith<'a, X>
+{
+  fn on_greet(self: Box<Self>) -> String
+  where
+    X: Greet,
+  {
+    self.0.greet()
+  }
+}
+
+struct CloneWith<'a, X>(&'a X);
+
+impl<'a, X> CloneCont<X, X> for CloneWith<'a, X>
+{
+  fn on_clone(self: Box<Self>) -> X
+  where
+    X: Clone,
+  {
+    self.0.clone()
+  }
+}
+
+struct CompareWith<X>(X, X);
+
+impl<X> OrdCont<X, std::cmp::Ordering> for CompareWith<X>
+{
+  fn on_ord(self: Box<Self>) -> std::cmp::Ordering
+  where
+    X: Ord,
+  {
+    self.0.cmp(&self.1)
+  }
+}
+
+#[test]
+fn test_define_constraint()
+{
+  assert_eq!(GreetConstraint::with_cont(GreetWith(&42)), "hello 42");
+
+  let dict: Dict<GreetConstraint, u32> = Dict::new();
+  let greeting =
+    dict.with_constraint(GreetConstraint::wrap_cont(GreetWith(&7)));
+  assert_eq!(greeting, "hello 7");
+}
+
+#[test]
+fn test_dict()
+{
+  // Neither function has any trait bound on X, with the constraint
+  // only available through the dictionary.
+  fn duplicate<X>(
+    dict: Dict<CloneConstraint, X>,
+    x: &X,
+  ) -> (X, X)
+  {
+    let x1 = dict.with_constraint(CloneConstraint::wrap_cont(CloneWith(x)));
+    let x2 = dict.with_constraint(CloneConstraint::wrap_cont(CloneWith(x)));
+    (x1, x2)
+  }
+
+  fn max_of<X>(
+    dict: Dict<OrdConstraint, X>,
+    x: X,
+    y: X,
+  ) -> X
+  {
+    struct Max<X>(X, X);
+
+    impl<X> OrdCont<X, X> for Max<X>
+    {
+      fn on_ord(self: Box<Self>) -> X
+      where
+        X: Ord,
+      {
+        self.0.max(self.1)
+      }
+    }
+
+    dict.with_constraint(OrdConstraint::wrap_cont(Max(x, y)))
+  }
+
+  let (a, b) = duplicate(Dict::new(), &"hi".to_string());
+  assert_eq!((a.as_str(), b.as_str()), ("hi", "hi"));
+
+  let dicts: Vec<Dict<OrdConstraint, u32>> = vec![Dict::new(); 2];
+  assert_eq!(max_of(dicts[0], 3, 5), 5);
+  assert_eq!(
+    dicts[1].with_constraint(OrdConstraint::wrap_cont(CompareWith(1, 2))),
+    std::cmp::Ordering::Less
+  );
+}
+
+struct EqualTo<'a, X>(&'a X, &'a X);
+
+impl<'a, X> PartialEqCont<X, bool> for EqualTo<'a, X>
+{
+  fn on_partial_eq(self: Box<Self>) -> bool
+  where
+    X: PartialEq,
+  {
+    self.0 == self.1
+  }
+}
+
+struct CloneThenDebug<'a, X>(&'a X);
+
+struct DebugClone<X>(X);
+
+impl<'a, X: 'a> CloneCont<X, BiApp<'a, DebugConstraint, X, String>>
+  for CloneThenDebug<'a, X>
+{
+  fn on_clone(self: Box<Self>) -> BiApp<'a, DebugConstraint, X, String>
+  where
+    X: Clone,
+  {
+    DebugConstraint::wrap_cont(DebugClone(self.0.clone()))
+  }
+}
+
+impl<X: Clone> DebugCont<X, String> for DebugClone<X>
+{
+  fn on_debug(self: Box<Self>) -> String
+  where
+    X: std::fmt::Debug,
+  {
+    format!("{:?} {:?}", self.0.clone(), self.0)
+  }
+}
+
+#[test]
+fn test_both_constraint()
+{
+  type CloneDebug = Both<CloneConstraint, DebugConstraint>;
+
+  let x = vec![1, 2];
+  let shown = CloneDebug::with_constraint(Both::wrap_cont(
+    CloneConstraint::wrap_cont(CloneThenDebug(&x)),
+  ));
+  assert_eq!(shown, "[1, 2] [1, 2]");
+
+  let dict: Dict<CloneDebug, Vec<u32>> = Dict::new();
+  let shown = dict.with_constraint(Both::wrap_cont(
+    CloneConstraint::wrap_cont(CloneThenDebug(&x)),
+  ));
+  assert_eq!(shown, "[1, 2] [1, 2]");
+
+  let (clone_dict, _) = dict.split();
+  let y = clone_dict.with_constraint(CloneConstraint::wrap_cont(CloneWith(&x)));
+  assert_eq!(y, x);
+}
+
+#[test]
+fn test_entails()
+{
+  fn equal<X>(
+    dict: Dict<OrdConstraint, X>,
+    x: &X,
+    y: &X,
+  ) -> bool
+  {
+    dict
+      .entail::<PartialEqConstraint>()
+      .with_constraint(PartialEqConstraint::wrap_cont(EqualTo(x, y)))
+  }
+
+  assert!(equal(Dict::new(), &"a", &"a"));
+  assert!(!equal(Dict::new(), &1, &2));
+
+  let ord_dict: Dict<OrdConstraint, u32> = Dict::new();
+  let eq_dict: Dict<EqConstraint, u32> = ord_dict.entail();
+  assert!(eq_dict
+    .entail::<PartialEqConstraint>()
+    .with_constraint(PartialEqConstraint::wrap_cont(EqualTo(&3, &3))));
+
+  let cont = <OrdConstraint as Entails<PartialEqConstraint>>::entail(
+    PartialEqConstraint::wrap_cont(EqualTo(&1, &2)),
+  );
+  assert!(!<OrdConstraint as HasConstraint<u32>>::with_constraint(cont));
+}
+
+#[test]
+fn test_trivial_constraint()
+{
+  struct Opaque;
+
+  struct Count;
+
+  impl TrivialCont<Opaque, u32> for Count
+  {
+    fn on_trivial(self: Box<Self>) -> u32
+    {
+      1
+    }
+  }
+
+  let dict: Dict<Trivial, Opaque> = Dict::new();
+  assert_eq!(dict.with_constraint(Trivial::wrap_cont(Count)), 1);
+}
@@ -0,0 +1,184 @@
+use std::cell::Cell;
+
+use crate::{
+  bi_type_app::*,
+  function::*,
+  functor::*,
+  type_app::*,
+};
+
+thread_local! {
+  static TRAVERSALS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// `Vec`, counting the number of times it is traversed by `fmap`.
+enum CountingVecF {}
+
+impl TypeCon for CountingVecF {}
+
+impl<'a, X: 'a> TypeApp<'a, X> for CountingVecF
+{
+  type Applied = Vec<X>;
+}
+
+impl Functor<FunctionMutF> for CountingVecF
+{
+  fn fmap<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mut mapper: BiApp<'b, FunctionMutF, A, B>,
+  ) -> App<'a, Self, B>
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    TRAVERSALS.with(|count| count.set(count.get() + 1));
+
+    wrap_app(
+      fa.get_applied()
+        .into_iter()
+        .map(|a| FunctionMutF::apply_mut(&mut mapper, a))
+        .collect::<Vec<B>>(),
+    )
+  }
+}
+
+fn traversals() -> usize
+{
+  TRAVERSALS.with(|count| count.get())
+}
+
+fn plus_one(x: u32) -> u32
+{
+  x + 1
+}
+
+#[test]
+fn test_coyoneda_single_traversal()
+{
+  type CoF = Coyoneda<CountingVecF, u32>;
+
+  let xs: App<CountingVecF, u32> = wrap_app(vec![1, 2, 3]);
+  let before = traversals();
+
+  let ys = lift_coyoneda(xs);
+  let ys = CoF::fmap(ys, wrap_fn_ptr(plus_one));
+  let ys = CoF::fmap(ys, wrap_closure(|x: u32| x * 10));
+
+  let mut seen = Vec::new();
+  let ys = CoF::fmap(
+    ys,
+    wrap_closure_mut(move |x: u32| {
+      seen.push(x);
+      format!("{}:{}", seen.len(), x)
+    }),
+  );
+
+  assert_eq!(traversals(), before);
+
+  let ys = lower_coyoneda(ys);
+
+  assert_eq!(traversals(), before + 1);
+  assert_eq!(ys.get_applied(), vec!["1:20", "2:30", "3:40"]);
+}
+
+#[test]
+fn test_map_coyoneda_boxed()
+{
+  type CoF = Coyoneda<CountingVecF, u32>;
+
+  let offset = 5;
+  let mut count = 0;
+
+  {
+    let xs: App<CountingVecF, u32> = wrap_app(vec![1, 2, 3]);
+    let before = traversals();
+
+    let ys = lift_coyoneda(xs);
+    let ys = map_coyoneda(ys, wrap_function(|x: u32| x + offset));
+    let ys = CoF::fmap(ys, wrap_fn_ptr(plus_one));
+    let ys = map_coyoneda(
+      ys,
+      wrap_function_mut(|x: u32| {
+        count += 1;
+        x * 2
+      }),
+    );
+
+    assert_eq!(traversals(), before);
+
+    let ys = lower_coyoneda(ys);
+
+    assert_eq!(traversals(), before + 1);
+    assert_eq!(ys.get_applied(), vec![14, 16, 18]);
+  }
+
+  assert_eq!(count, 3);
+}
+
+#[test]
+fn test_yoneda_single_traversal()
+{
+  type YoF = Yoneda<CountingVecF, String>;
+
+  let xs: App<CountingVecF, u32> = wrap_app(vec![1, 2, 3]);
+  let before = traversals();
+
+  let offset = 5;
+  let ys: App<YoF, u32> = lift_yoneda(xs);
+  let ys = YoF::fmap(ys, wrap_fn_ptr(plus_one));
+  let ys = map_yoneda(ys, wrap_function(|x: u32| x + offset));
+  let ys = YoF::fmap(ys, wrap_closure(|x: u32| x * 10));
+
+  let mut seen = Vec::new();
+  let ys = YoF::fmap(
+    ys,
+    wrap_closure_mut(move |x: u32| {
+      seen.push(x);
+      format!("{}:{}", seen.len(), x)
+    }),
+  );
+
+  assert_eq!(traversals(), before);
+
+  let ys = lower_yoneda(ys);
+
+  assert_eq!(traversals(), before + 1);
+  assert_eq!(ys.get_applied(), vec!["1:70", "2:80", "3:90"]);
+}
+
+#[test]
+fn test_direct_fmap_traversals()
+{
+  let xs: App<CountingVecF, u32> = wrap_app(vec![1, 2, 3]);
+  let before = traversals();
+
+  let ys = CountingVecF::fmap(xs, wrap_function_mut(plus_one));
+  let ys = CountingVecF::fmap(ys, wrap_function_mut(|x| x * 10));
+
+  assert_eq!(traversals(), before + 2);
+  assert_eq!(ys.get_applied(), vec![20, 30, 40]);
+}
+
+#[test]
+fn test_coyoneda_non_functor()
+{
+  /// A type constructor without a `Functor` instance.
+  enum BoxedF {}
+
+  impl TypeCon for BoxedF {}
+
+  impl<'a, X: 'a> TypeApp<'a, X> for BoxedF
+  {
+    type Applied = Box<X>;
+  }
+
+  type CoF = Coyoneda<BoxedF, u8>;
+
+  // The mappers are only run when lowering, which requires a
+  // `Functor` instance.
+  let x = lift_coyoneda::<BoxedF, u8>(wrap_app(Box::new(1)));
+  let x = CoF::fmap(x, wrap_closure(|x: u8| x as u32 + 1));
+  let x = CoF::fmap(x, wrap_fn_ptr(|_: u32| -> u64 { panic!("lowered") }));
+
+  drop(x);
+}
@@ -0,0 +1,168 @@
+use std::fmt::Debug;
+
+use crate::{
+  constraint::*,
+  poly_fn,
+  type_app::*,
+};
+
+struct Length;
+
+impl<'a> ExistsCont<'a, VecF> for Length
+{
+  type Output = usize;
+
+  fn on_exists<X: 'a>(
+    self,
+    fx: App<'a, VecF, X>,
+  ) -> usize
+  {
+    fx.get_applied().len()
+  }
+}
+
+struct LengthPlus(usize);
+
+impl<'a> ExistsCont<'a, VecF> for LengthPlus
+{
+  type Output = usize;
+
+  fn on_exists<X: 'a>(
+    self,
+    fx: App<'a, VecF, X>,
+  ) -> usize
+  {
+    fx.get_applied().len() + self.0
+  }
+}
+
+struct IsSome;
+
+impl<'a> ExistsCont<'a, OptionF> for IsSome
+{
+  type Output = bool;
+
+  fn on_exists<X: 'a>(
+    self,
+    fx: App<'a, OptionF, X>,
+  ) -> bool
+  {
+    fx.get_applied().is_some()
+  }
+}
+
+struct ShowAll;
+
+struct ShowOne<'a, X>(&'a X);
+
+impl<'a, X> DebugCont<X, String> for ShowOne<'a, X>
+{
+  fn on_debug(self: Box<Self>) -> String
+  where
+    X: Debug,
+  {
+    format!("{:?}", self.0)
+  }
+}
+
+impl<'a> ExistsWithCont<'a, VecF, DebugConstraint> for ShowAll
+{
+  type Output = Vec<String>;
+
+  fn on_exists_with<X: 'a>(
+    self,
+    fx: App<'a, VecF, X>,
+    dict: Dict<DebugConstraint, X>,
+  ) -> Vec<String>
+  {
+    fx.get_applied()
+      .iter()
+      .map(|x| dict.with_constraint(DebugConstraint::wrap_cont(ShowOne(x))))
+      .collect()
+  }
+}
+
+poly_fn! {
+  struct Head<X>(fx: App<VecF, X>) -> App<OptionF, X> {
+    wrap_app(fx.get_applied().into_iter().next())
+  }
+}
+
+poly_fn! {
+  struct ToVec<X>(fx: App<OptionF, X>) -> App<VecF, X> {
+    wrap_app(fx.get_applied().into_iter().collect())
+  }
+}
+
+#[test]
+fn test_exists()
+{
+  let items: Vec<Exists<VecF, Length>> = vec![
+    Exists::new(wrap_app(vec![1, 2, 3])),
+    Exists::new(wrap_app(vec!["a"])),
+    Exists::new(wrap_app(Vec::<()>::new())),
+  ];
+
+  let lengths: Vec<usize> =
+    items.into_iter().map(|ex| ex.elim(Length)).collect();
+
+  assert_eq!(lengths, vec![3, 1, 0]);
+}
+
+#[test]
+fn test_exists_cont_value()
+{
+  let items: Vec<Exists<VecF, LengthPlus>> = vec![
+    Exists::new(wrap_app(vec![1, 2, 3])),
+    Exists::new(wrap_app(vec!["a"])),
+  ];
+
+  let lengths: Vec<usize> = items
+    .into_iter()
+    .enumerate()
+    .map(|(i, ex)| ex.elim(LengthPlus(i * 10)))
+    .collect();
+
+  assert_eq!(lengths, vec![3, 11]);
+}
+
+#[test]
+fn test_exists_with()
+{
+  let items: Vec<ExistsWith<VecF, DebugConstraint, ShowAll>> = vec![
+    ExistsWith::new(wrap_app(vec![1, 2])),
+    ExistsWith::new(wrap_app(vec!["a"])),
+    ExistsWith::new(wrap_app(vec![Some(1.5)])),
+  ];
+
+  let shown: Vec<Vec<String>> =
+    items.into_iter().map(|ex| ex.elim(ShowAll)).collect();
+
+  assert_eq!(shown, vec![vec!["1", "2"], vec!["\"a\""], vec!["Some(1.5)"]]);
+}
+
+#[test]
+fn test_exists_lift()
+{
+  type HeadIsSome = LiftCont<'static, Head, OptionF, IsSome>;
+
+  let items: Vec<Exists<VecF, HeadIsSome>> = vec![
+    Exists::new(wrap_app(vec![1, 2, 3])),
+    Exists::new(wrap_app(Vec::<&str>::new())),
+  ];
+
+  let results: Vec<bool> = items
+    .into_iter()
+    .map(|ex| ex.lift(&Head).elim(IsSome))
+    .collect();
+
+  assert_eq!(results, vec![true, false]);
+
+  let item: ExistsWith<
+    OptionF,
+    DebugConstraint,
+    LiftCont<ToVec, VecF, ShowAll>,
+  > = ExistsWith::new(wrap_app(Some(4)));
+
+  assert_eq!(item.lift(&ToVec).elim(ShowAll), vec!["4"]);
+}
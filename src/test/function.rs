@@ -0,0 +1,67 @@
+use crate::{
+  function::*,
+  functor::*,
+  type_app::*,
+};
+
+fn double(x: u32) -> u32
+{
+  x * 2
+}
+
+#[test]
+fn test_fn_ptr_fmap()
+{
+  let xs: App<VecF, u32> = wrap_app(vec![1, 2, 3]);
+  let ys = VecF::fmap(xs, wrap_fn_ptr(double));
+
+  assert_eq!(ys.get_applied(), vec![2, 4, 6]);
+
+  let f = wrap_fn_ptr(|x: u8| x.to_string());
+  assert_eq!(FnPtrF::apply(&f, 1), "1");
+  assert_eq!(FnPtrF::apply_once(f, 2), "2");
+}
+
+#[test]
+fn test_closure_fmap()
+{
+  let factor = 3;
+  let xs: App<VecF, u32> = wrap_app(vec![1, 2, 3]);
+  let ys = VecF::fmap(xs, wrap_closure(move |x| x * factor));
+
+  assert_eq!(ys.get_applied(), vec![3, 6, 9]);
+
+  let total = VecF::fold_left(
+    wrap_app(vec![1, 2, 3]),
+    0,
+    wrap_closure(|(acc, x): (u32, u32)| acc + x),
+  );
+
+  assert_eq!(total, 6);
+}
+
+#[test]
+fn test_closure_mut_fmap()
+{
+  let mut count = 0;
+  let xs: App<VecF, &str> = wrap_app(vec!["a", "b", "c"]);
+  let ys = VecF::fmap(
+    xs,
+    wrap_closure_mut(move |x: &str| {
+      count += 1;
+      format!("{}{}", x, count)
+    }),
+  );
+
+  assert_eq!(ys.get_applied(), vec!["a1", "b2", "c3"]);
+}
+
+#[test]
+fn test_closure_once_fmap()
+{
+  let suffix = "!".to_string();
+  let x: App<OptionF, String> = wrap_app(Some("hello".to_string()));
+  let y = OptionF::fmap(x, wrap_closure_once(move |x: String| x + &suffix));
+
+  assert_eq!(y.get_applied(), Some("hello!".to_string()));
+}
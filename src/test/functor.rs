@@ -1,3 +1,8 @@
+use std::{
+  cell::Cell,
+  panic,
+};
+
 use crate::{
   function::*,
   functor::*,
@@ -43,3 +48,161 @@ fn test_fmap_vec_option()
     assert_eq!(Some("84".to_string()), x);
   }
 }
+
+#[test]
+fn test_fmap_in_place()
+{
+  let mut xs: App<VecF, u32> = wrap_app(Vec::with_capacity(16));
+  xs.get_applied_borrow_mut().extend([1, 2, 3]);
+  let ptr = xs.get_applied_borrow().as_ptr();
+
+  let mut total = 0;
+  VecF::fmap_in_place(
+    &mut xs,
+    wrap_function_mut(|x: &mut u32| {
+      total += *x;
+      *x *= 10;
+    }),
+  );
+
+  assert_eq!(total, 6);
+  assert_eq!(xs.get_applied_borrow().as_ptr(), ptr);
+  assert_eq!(xs.get_applied_borrow().capacity(), 16);
+  assert_eq!(xs.get_applied(), vec![10, 20, 30]);
+
+  let mut x: App<OptionF, String> = wrap_app(Some("a".to_string()));
+  OptionF::fmap_in_place(
+    &mut x,
+    wrap_function_once(|x: &mut String| x.push('b')),
+  );
+  assert_eq!(x.get_applied(), Some("ab".to_string()));
+
+  let mut x: App<ResultF<u8>, String> = wrap_app(Ok("a".to_string()));
+  ResultF::fmap_in_place(&mut x, wrap_function(|x: &mut String| x.push('b')));
+  assert_eq!(x.get_applied(), Ok("ab".to_string()));
+
+  let mut x: App<ResultF<u8>, String> = wrap_app(Err(1));
+  ResultF::fmap_in_place(&mut x, wrap_function(|x: &mut String| x.push('b')));
+  assert_eq!(x.get_applied(), Err(1));
+
+  let mut x: App<Identity, Vec<u8>> = wrap_app(vec![1]);
+  Identity::fmap_in_place(
+    &mut x,
+    wrap_function_once(|x: &mut Vec<u8>| x.push(2)),
+  );
+  assert_eq!(x.get_applied(), vec![1, 2]);
+}
+
+#[test]
+fn test_fmap_in_place_panic()
+{
+  let mut x: App<Identity, Vec<u8>> = wrap_app(vec![1]);
+
+  let res = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+    Identity::fmap_in_place(
+      &mut x,
+      wrap_function_once(|x: &mut Vec<u8>| {
+        x.push(2);
+        panic!("mapper failed");
+      }),
+    )
+  }));
+
+  assert!(res.is_err());
+  assert_eq!(x.get_applied(), vec![1, 2]);
+}
+
+#[test]
+fn test_fmap_in_place_compose()
+{
+  let xs1: Vec<App<OptionF, u64>> =
+    vec![wrap_app(Some(1)), wrap_app(None), wrap_app(Some(2))];
+  let mut xs2: App<VecOptionF, u64> = wrap_app(wrap_app(xs1));
+
+  let mut count = 0;
+  VecOptionF::fmap_in_place(
+    &mut xs2,
+    wrap_function_mut(|x: &mut u64| {
+      count += 1;
+      *x += 1;
+    }),
+  );
+  VecOptionF::fmap_in_place(&mut xs2, wrap_function(|x: &mut u64| *x *= 2));
+
+  let xs3: Vec<_> = xs2
+    .get_applied()
+    .get_applied()
+    .into_iter()
+    .map(|x| x.get_applied())
+    .collect();
+
+  assert_eq!(count, 2);
+  assert_eq!(xs3, vec![Some(4), None, Some(6)]);
+
+  let mut x: App<ComposeApp<OptionF, OptionF>, u8> =
+    wrap_app(wrap_app(Some(wrap_app(Some(1)))));
+  ComposeApp::<OptionF, OptionF>::fmap_in_place(
+    &mut x,
+    wrap_function_once(|x: &mut u8| *x += 1),
+  );
+  let x = x.get_applied().get_applied().map(|x| x.get_applied());
+  assert_eq!(x, Some(Some(2)));
+}
+
+#[test]
+fn test_fmap_reuses_allocation()
+{
+  let mut xs: Vec<u32> = Vec::with_capacity(8);
+  xs.extend([1, 2, 3]);
+  let ptr = xs.as_ptr() as usize;
+
+  let ys =
+    VecF::fmap(wrap_app(xs), wrap_function(|x: u32| x as f32)).get_applied();
+
+  assert_eq!(ys.as_ptr() as usize, ptr);
+  assert_eq!(ys.capacity(), 8);
+  assert_eq!(ys, vec![1.0, 2.0, 3.0]);
+
+  let zs = VecF::fmap(wrap_app(ys), wrap_function(|y: f32| y as u64 * 2))
+    .get_applied();
+
+  assert_eq!(zs, vec![2, 4, 6]);
+}
+
+#[test]
+fn test_fmap_reuse_panic()
+{
+  thread_local! {
+    static DROPS: Cell<usize> = const { Cell::new(0) };
+  }
+
+  struct Counted(u32);
+
+  impl Drop for Counted
+  {
+    fn drop(&mut self)
+    {
+      DROPS.with(|drops| drops.set(drops.get() + 1));
+    }
+  }
+
+  let xs: Vec<Counted> = (0..5).map(Counted).collect();
+
+  let res = panic::catch_unwind(|| {
+    VecF::fmap(
+      wrap_app(xs),
+      wrap_function(|x: Counted| {
+        if x.0 == 2 {
+          panic!("mapper failed");
+        }
+        Counted(x.0 + 10)
+      }),
+    )
+  });
+
+  assert!(res.is_err());
+
+  // The two mapped results, the three elements passed to the mapper,
+  // and the two elements it never reached.
+  assert_eq!(DROPS.with(|drops| drops.get()), 7);
+}
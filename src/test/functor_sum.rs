@@ -0,0 +1,78 @@
+use crate::{
+  function::*,
+  functor::*,
+  functor_sum::*,
+  product::*,
+  type_app::*,
+};
+
+type Sup = FunctorSum<VecF, FunctorSum<OptionF, Identity>>;
+
+#[test]
+fn test_inject_project()
+{
+  let sx: App<Sup, u32> = inject::<Identity, Sup, _, _>(wrap_app(42));
+
+  assert!(prj::<VecF, Sup, _, _>(sx).is_none());
+
+  let sx: App<Sup, u32> = inject::<Identity, Sup, _, _>(wrap_app(42));
+  let x = prj::<Identity, Sup, _, _>(sx).unwrap().get_applied();
+  assert_eq!(x, 42);
+
+  let sx: App<Sup, u32> = inject::<OptionF, Sup, _, _>(wrap_app(Some(1)));
+  let x = prj::<OptionF, Sup, _, _>(sx).unwrap().get_applied();
+  assert_eq!(x, Some(1));
+}
+
+#[test]
+fn test_functor_sum_fmap()
+{
+  let sx: App<Sup, u32> = inject::<VecF, Sup, _, _>(wrap_app(vec![1, 2, 3]));
+  let sy = Sup::fmap(sx, wrap_function_mut(|x: u32| x.to_string()));
+
+  let ys = prj::<VecF, Sup, _, _>(sy).unwrap().get_applied();
+  assert_eq!(ys, vec!["1", "2", "3"]);
+
+  let sx: App<Sup, u32> = inject::<OptionF, Sup, _, _>(wrap_app(Some(2)));
+  let total = Sup::fold_left(sx, 10, wrap_function_mut(|(b, a)| b + a));
+  assert_eq!(total, 12);
+}
+
+#[test]
+fn test_functor_product()
+{
+  type Pair = FunctorProduct<VecF, OptionF>;
+
+  let px: App<Pair, u32> =
+    wrap_app(Cons(wrap_app(vec![1, 2, 3]), wrap_app(Some(4))));
+
+  let py = Pair::fmap(px, wrap_function(|x: u32| x * 2));
+
+  let total = Pair::fold_left(py, 0, wrap_function(|(b, a)| b + a));
+  assert_eq!(total, 20);
+
+  let px: App<Pair, u32> =
+    wrap_app(Cons(wrap_app(vec![1, 2, 3]), wrap_app(Some(4))));
+
+  let res = Pair::traverse::<OptionF, _, _>(
+    px,
+    wrap_function_mut(|x: u32| wrap_app(x.checked_sub(1))),
+  )
+  .get_applied()
+  .unwrap();
+
+  let Cons(xs, x) = res.get_applied();
+  assert_eq!(xs.get_applied(), vec![0, 1, 2]);
+  assert_eq!(x.get_applied(), Some(3));
+
+  let px: App<Pair, u32> =
+    wrap_app(Cons(wrap_app(vec![1, 0, 3]), wrap_app(Some(4))));
+
+  let res = Pair::traverse::<OptionF, _, _>(
+    px,
+    wrap_function_mut(|x: u32| wrap_app(x.checked_sub(1))),
+  )
+  .get_applied();
+
+  assert!(res.is_none());
+}
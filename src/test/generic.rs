@@ -0,0 +1,125 @@
+use core::any::type_name;
+
+use crate::{
+  bi_type_app::*,
+  constraint::*,
+  product::*,
+  reference::*,
+  row::*,
+  type_app::*,
+};
+
+/// `BiApp<PairF, X, Y> ~ (X, Y)`, inhabited so that it can be used
+/// as its own witness.
+struct PairF;
+
+impl BiTypeCon for PairF {}
+
+impl<'a, X: 'a, Y: 'a> BiTypeApp<'a, X, Y> for PairF
+{
+  type Applied = (X, Y);
+}
+
+/// Continuations that can only name the applied type once the
+/// application constraint is available.
+struct TypeName;
+
+impl<'a, F: ?Sized, X: 'a + ?Sized> TypeAppCont<'a, F, X, &'static str>
+  for TypeName
+{
+  fn on_type_app(self) -> &'static str
+  where
+    F: TypeApp<'a, X>,
+  {
+    type_name::<F::Applied>()
+  }
+}
+
+impl<'a, F: 'a, X: 'a, Y: 'a> BiTypeAppGenericCont<'a, F, X, Y, &'static str>
+  for TypeName
+{
+  fn on_type_app(self) -> &'static str
+  where
+    F: BiTypeApp<'a, X, Y>,
+  {
+    type_name::<F::Applied>()
+  }
+}
+
+impl<'a, Row: 'a, F: 'a> RowAppGenericCont<'a, Row, F, &'static str>
+  for TypeName
+{
+  fn on_row_app(self) -> &'static str
+  where
+    F: TypeCon,
+    Row: RowApp<'a, F>,
+  {
+    type_name::<Row::Applied>()
+  }
+}
+
+fn generic_name<F, X>() -> &'static str
+where
+  F: TypeAppGeneric,
+{
+  F::with_type_app::<X, _, _>(TypeName)
+}
+
+fn type_app_name<'a, W, F: 'a, X: 'a>(witness: W) -> &'static str
+where
+  W: TypeAppWitness<'a, F, X>,
+{
+  with_type_app_witness(witness, TypeName)
+}
+
+fn bi_type_app_name<'a, W, F: 'a, X: 'a, Y: 'a>(witness: W) -> &'static str
+where
+  W: BiTypeAppWitness<'a, F, X, Y>,
+{
+  with_bi_type_app_witness(witness, TypeName)
+}
+
+fn row_app_name<'a, W, Row: 'a, F: 'a>(witness: W) -> &'static str
+where
+  F: TypeCon,
+  W: RowAppWitness<'a, Row, F>,
+{
+  with_row_app_witness(witness, TypeName)
+}
+
+#[test]
+fn test_type_app_generic()
+{
+  assert!(generic_name::<ResultF<String>, u8>().contains("Result<u8,"));
+  assert_eq!(generic_name::<Const<bool>, u8>(), "bool");
+  assert!(generic_name::<AppF<VecF>, u8>().contains("App<"));
+  assert!(generic_name::<ComposeApp<VecF, OptionF>, u8>().contains("App<"));
+  let name = generic_name::<Partial<DebugConstraint, u8>, u16>();
+  assert!(name.contains("DebugCont<u8, u16>"));
+
+  let name = generic_name::<Compose<Borrow, OptionF>, u8>();
+  assert!(name.starts_with('&'));
+  assert!(name.contains("Option<u8>"));
+
+  let name = generic_name::<Compose<VecF, OptionF>, u8>();
+  assert!(name.contains("Vec<core::option::Option<u8>>"));
+
+  let name =
+    generic_name::<Compose<Compose<OptionF, VecF>, ResultF<bool>>, u8>();
+  assert!(name.contains("Option<alloc::vec::Vec<core::result::Result<u8,"));
+}
+
+#[test]
+fn test_app_witnesses()
+{
+  let name = type_app_name::<_, ArrayF<2>, u8>(ArrayF::<2>);
+  assert_eq!(name, "[u8; 2]");
+
+  let name = bi_type_app_name::<_, PairF, u8, u16>(PairF);
+  assert_eq!(name, "(u8, u16)");
+
+  let witness = Cons(1u8, Top);
+  let name = row_app_name::<_, Cons<u8, Top>, OptionF>(witness);
+  assert!(name.contains("Cons<"));
+  assert!(name.contains("Option"));
+}
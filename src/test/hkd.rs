@@ -0,0 +1,101 @@
+use crate::{
+  define_hkd,
+  function::*,
+  poly_fn,
+  row::*,
+  type_app::*,
+};
+
+define_hkd! {
+  ConfigRow,
+  struct Config {
+    host: String,
+    port: u16,
+    verbose: bool,
+  }
+}
+
+#[test]
+fn test_hkd_validate()
+{
+  let config: Config<OptionF> = Config {
+    host: wrap_app(Some("localhost".to_string())),
+    port: wrap_app(Some(8080)),
+    verbose: wrap_app(None),
+  };
+
+  assert!(config.validate().is_none());
+
+  let config: Config<OptionF> = Config {
+    host: wrap_app(Some("localhost".to_string())),
+    port: wrap_app(Some(8080)),
+    verbose: wrap_app(Some(true)),
+  };
+
+  let config = config.validate().unwrap();
+
+  assert_eq!(config.host.get_applied(), "localhost");
+  assert_eq!(config.port.get_applied(), 8080);
+  assert!(config.verbose.get_applied());
+}
+
+#[test]
+fn test_hkd_merge()
+{
+  let defaults: Config<OptionF> = Config {
+    host: wrap_app(Some("localhost".to_string())),
+    port: wrap_app(Some(80)),
+    verbose: wrap_app(Some(false)),
+  };
+
+  let user: Config<OptionF> = Config {
+    host: wrap_app(None),
+    port: wrap_app(Some(8080)),
+    verbose: wrap_app(None),
+  };
+
+  let config = user.merge(defaults);
+
+  assert_eq!(config.host.get_applied(), Some("localhost".to_string()));
+  assert_eq!(config.port.get_applied(), Some(8080));
+  assert_eq!(config.verbose.get_applied(), Some(false));
+}
+
+#[test]
+fn test_hkd_sequence()
+{
+  let config: Config<ResultF<String>> = Config {
+    host: wrap_app(Ok("localhost".to_string())),
+    port: wrap_app(Err("invalid port".to_string())),
+    verbose: wrap_app(Err("invalid verbosity".to_string())),
+  };
+
+  let res = config.sequence::<FunctionF>().get_applied();
+
+  assert_eq!(res.err(), Some("invalid port".to_string()));
+}
+
+#[test]
+fn test_hkd_lift_row()
+{
+  poly_fn! {
+    struct Head<X>(fx: App<VecF, X>) -> App<OptionF, X> {
+      wrap_app(fx.get_applied().into_iter().next())
+    }
+  }
+
+  let config: Config<VecF> = Config {
+    host: wrap_app(vec!["localhost".to_string(), "example.com".to_string()]),
+    port: wrap_app(vec![8080]),
+    verbose: wrap_app(vec![true, false]),
+  };
+
+  let config: AppRow<ConfigRow, OptionF> =
+    ConfigRow::lift(&Head, wrap_row::<ConfigRow, VecF>(config));
+
+  let config = config.get_applied().validate().unwrap();
+
+  assert_eq!(config.host.get_applied(), "localhost");
+  assert_eq!(config.port.get_applied(), 8080);
+  assert!(config.verbose.get_applied());
+}
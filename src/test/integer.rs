@@ -0,0 +1,42 @@
+use crate::{
+  integer::*,
+  nat::*,
+  test::assert_same,
+};
+
+type N0 = Z;
+type N2 = S<S<N0>>;
+type N3 = S<N2>;
+type N5 = Plus<N2, N3>;
+
+#[test]
+fn test_integer_value()
+{
+  assert_eq!(<Pos<N0>>::VALUE, 0);
+  assert_eq!(<Pos<N5>>::VALUE, 5);
+  assert_eq!(<Neg<N3>>::VALUE, -3);
+  assert_eq!(<Neg<B0<B1<U0>>>>::VALUE, -2);
+}
+
+#[test]
+fn test_integer_negate()
+{
+  assert_same::<Negated<Pos<N0>>, Pos<N0>>();
+  assert_same::<Negated<Pos<N3>>, Neg<N3>>();
+  assert_same::<Negated<Neg<N3>>, Pos<N3>>();
+  assert_same::<Negated<Negated<Pos<N5>>>, Pos<N5>>();
+}
+
+#[test]
+fn test_integer_addition()
+{
+  assert_same::<IntPlus<Pos<N2>, Pos<N3>>, Pos<N5>>();
+  assert_same::<IntPlus<Neg<N2>, Neg<N3>>, Neg<N5>>();
+  assert_same::<IntPlus<Pos<N2>, Neg<N3>>, Neg<S<N0>>>();
+  assert_same::<IntPlus<Neg<N2>, Pos<N3>>, Pos<S<N0>>>();
+  assert_same::<IntPlus<Pos<N3>, Neg<N3>>, Pos<N0>>();
+
+  assert_same::<IntMinus<Pos<N2>, Pos<N5>>, Neg<N3>>();
+  assert_same::<IntMinus<Neg<N2>, Neg<N2>>, Pos<N0>>();
+  assert_eq!(<IntMinus<Pos<N2>, Neg<N5>>>::VALUE, 7);
+}
@@ -0,0 +1,56 @@
+use crate::{
+  label,
+  label::*,
+  product::*,
+  record,
+  sum::*,
+  variant,
+};
+
+label!(name, age, email);
+
+type Person = Cons<
+  Field<name, String>,
+  Cons<Field<age, u32>, Cons<Field<email, Option<String>>, Top>>,
+>;
+
+#[test]
+fn test_record_lookup()
+{
+  let mut person: Person = record! {
+    name: "Alice".to_string(),
+    age: 42,
+    email: None,
+  };
+
+  assert_eq!(person.get::<name, _, _>(), "Alice");
+  assert_eq!(*person.get::<age, _, _>(), 42);
+  assert_eq!(*person.get::<email, _, _>(), None);
+
+  *person.get_mut::<age, _, _>() += 1;
+  *person.get_mut::<email, _, _>() = Some("alice@example.com".to_string());
+
+  assert_eq!(*person.get::<age, _, _>(), 43);
+
+  let email = person.take::<email, _, _>();
+  assert_eq!(email, Some("alice@example.com".to_string()));
+}
+
+#[test]
+fn test_variant_injection()
+{
+  type Contact = Union<Field<email, String>, Union<Field<age, u32>, Bottom>>;
+
+  let contact: Contact = variant!(age, 42);
+
+  assert!(contact.get::<email, _, _>().is_none());
+  assert_eq!(contact.get::<age, _, _>(), Some(&42));
+
+  let contact = match HasVariant::<email, String, _>::take_variant(contact) {
+    Ok(_) => panic!("expected the age variant"),
+    Err(contact) => contact,
+  };
+
+  let res = HasVariant::<age, u32, _>::take_variant(contact);
+  assert_eq!(res.ok(), Some(42));
+}
@@ -0,0 +1,130 @@
+use core::hash::{
+  Hash,
+  Hasher,
+};
+use std::collections::{
+  hash_map::DefaultHasher,
+  HashSet,
+};
+
+use crate::{
+  reference::*,
+  type_app::*,
+};
+
+fn hash_of<T: Hash>(value: &T) -> u64
+{
+  let mut hasher = DefaultHasher::new();
+  value.hash(&mut hasher);
+  hasher.finish()
+}
+
+#[test]
+fn test_app_eq()
+{
+  let xs: App<VecF, u8> = wrap_app(vec![1, 2, 3]);
+  let ys: App<VecF, u8> = wrap_app(vec![1, 2, 3]);
+  let zs: App<VecF, u8> = wrap_app(vec![1, 2]);
+
+  assert_eq!(xs, ys);
+  assert_ne!(xs, zs);
+
+  let x: App<ResultF<String>, u8> = wrap_app(Ok(1));
+  let y: App<ResultF<String>, u8> = wrap_app(Err("error".to_string()));
+  assert_ne!(x, y);
+
+  let x: App<Const<&str>, u8> = wrap_app("a");
+  assert_eq!(x, wrap_app("a"));
+}
+
+#[test]
+fn test_app_ord()
+{
+  let mut apps: Vec<App<OptionF, u8>> =
+    vec![wrap_app(Some(3)), wrap_app(None), wrap_app(Some(1))];
+
+  apps.sort();
+
+  let apps: Vec<_> = apps.into_iter().map(|x| x.get_applied()).collect();
+  assert_eq!(apps, vec![None, Some(1), Some(3)]);
+
+  let x: App<ArrayF<2>, f32> = wrap_app([1.0, f32::NAN]);
+  let y: App<ArrayF<2>, f32> = wrap_app([1.0, 2.0]);
+  assert_eq!(x.partial_cmp(&y), None);
+}
+
+#[test]
+fn test_app_debug()
+{
+  let x: App<OptionF, &str> = wrap_app(Some("a"));
+  assert_eq!(format!("{:?}", x), "Some(\"a\")");
+
+  let value = 42;
+  let x: App<Borrow, i32> = wrap_app(&value);
+  assert_eq!(format!("{:?}", x), "42");
+
+  let x: App<Identity, (u8, bool)> = wrap_app((1, true));
+  assert_eq!(format!("{:?}", x), "(1, true)");
+}
+
+#[test]
+fn test_app_clone()
+{
+  let x: App<BoxF, String> = wrap_app(Box::new("hello".to_string()));
+  let y = x.clone();
+
+  assert_eq!(*x.get_applied(), "hello");
+  assert_eq!(*y.get_applied(), "hello");
+
+  let mut value = 1;
+  let x: App<BorrowMut, i32> = wrap_app(&mut value);
+  assert_eq!(format!("{:?}", x), "1");
+}
+
+#[test]
+fn test_app_hash()
+{
+  let x: App<VecF, u8> = wrap_app(vec![1, 2, 3]);
+  assert_eq!(hash_of(&x), hash_of(&vec![1u8, 2, 3]));
+
+  let set: HashSet<App<OptionF, u8>> =
+    vec![wrap_app(Some(1)), wrap_app(Some(1)), wrap_app(None)]
+      .into_iter()
+      .collect();
+
+  assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_nested_app()
+{
+  let x: App<ComposeApp<VecF, OptionF>, u8> =
+    wrap_app(wrap_app(vec![wrap_app(Some(1)), wrap_app(None)]));
+  let y = x.clone();
+
+  assert_eq!(x, y);
+  assert_eq!(format!("{:?}", y), "[Some(1), None]");
+  assert_eq!(hash_of(&x), hash_of(&y));
+
+  let x: App<AppF<OptionF>, u8> = wrap_app(wrap_app(Some(1)));
+  let y: App<AppF<OptionF>, u8> = wrap_app(wrap_app(Some(2)));
+  assert!(x < y);
+}
+
+#[test]
+fn test_partial_element()
+{
+  fn assert_eq<T: Eq>() {}
+  fn assert_ord<T: Ord>() {}
+
+  assert_eq::<App<ResultF<String>, u32>>();
+  assert_ord::<App<Const<u8>, bool>>();
+
+  let x: App<ResultF<f64>, u32> = wrap_app(Err(f64::NAN));
+  assert!(x != x);
+  assert_eq!(x.partial_cmp(&x), None);
+
+  let x: App<Const<f64>, u8> = wrap_app(1.0);
+  let y: App<Const<f64>, u8> = wrap_app(2.0);
+  assert!(x < y);
+}
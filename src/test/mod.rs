@@ -1,3 +1,34 @@
+pub mod array;
+pub mod boolean;
 pub mod constraint;
+pub mod coyoneda;
+pub mod exists;
+pub mod function;
 pub mod functor;
+pub mod functor_sum;
+pub mod generic;
+pub mod hkd;
+pub mod integer;
+pub mod label;
+pub mod lifted;
+pub mod nat;
 pub mod nat_trans;
+pub mod packed;
+pub mod par;
+pub mod poly_fn;
+pub mod refl;
+pub mod row;
+pub mod send;
+pub mod type_eq;
+pub mod units;
+pub mod vect;
+
+use crate::refl::Refl;
+
+/// Assert that `T1` and `T2` are the same type, failing to compile
+/// otherwise.
+fn assert_same<T1, T2>()
+where
+  T1: Refl<Refl = T2>,
+{
+}
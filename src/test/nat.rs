@@ -0,0 +1,142 @@
+use std::cmp::Ordering;
+
+use crate::{
+  nat::*,
+  test::assert_same,
+};
+
+type N0 = Z;
+type N1 = S<N0>;
+type N2 = S<N1>;
+type N3 = S<N2>;
+type N5 = Plus<N2, N3>;
+type N6 = Times<N2, N3>;
+
+fn ordering<N, M>() -> Ordering
+where
+  N: Compare<M>,
+  M: Nat,
+{
+  <Compared<N, M> as Comparison>::ORDERING
+}
+
+#[test]
+fn test_nat_value()
+{
+  assert_eq!(N0::VALUE, 0);
+  assert_eq!(N3::VALUE, 3);
+  assert_eq!(<S<N6>>::VALUE, 7);
+}
+
+#[test]
+fn test_nat_arithmetic()
+{
+  assert_same::<Plus<N0, N3>, N3>();
+  assert_same::<Plus<N2, N3>, S<S<N3>>>();
+
+  assert_same::<Minus<N5, N2>, N3>();
+  assert_same::<Minus<N3, N3>, N0>();
+
+  assert_same::<SaturatingMinus<N5, N2>, N3>();
+  assert_same::<SaturatingMinus<N2, N5>, N0>();
+
+  assert_same::<Times<N0, N3>, N0>();
+  assert_same::<Times<N3, N1>, N3>();
+  assert_eq!(N6::VALUE, 6);
+  assert_eq!(<Times<N5, N6>>::VALUE, 30);
+
+  assert_same::<Minimum<N2, N5>, N2>();
+  assert_same::<Minimum<N5, N2>, N2>();
+  assert_same::<Maximum<N2, N5>, N5>();
+  assert_same::<Maximum<N5, N2>, N5>();
+}
+
+#[test]
+fn test_nat_compare()
+{
+  assert_same::<Compared<N2, N5>, Lt>();
+  assert_same::<Compared<N5, N5>, Eq>();
+  assert_same::<Compared<N6, N5>, Gt>();
+
+  assert_eq!(ordering::<N0, N1>(), Ordering::Less);
+  assert_eq!(ordering::<N3, N3>(), Ordering::Equal);
+  assert_eq!(ordering::<N3, N0>(), Ordering::Greater);
+}
+
+type B2 = B0<B1<U0>>;
+type B3 = B1<B1<U0>>;
+type B5 = B1<B0<B1<U0>>>;
+type B24 = B0<B0<B0<B3>>>;
+type B1000 = B0<B0<B0<B1<B0<B1<B1<B1<B1<B1<U0>>>>>>>>>>;
+type B1024 = B0<B0<B0<B0<B0<B0<B0<B0<B0<B0<B1<U0>>>>>>>>>>>;
+
+#[test]
+fn test_binary_value()
+{
+  assert_eq!(U0::VALUE, 0);
+  assert_eq!(B5::VALUE, 5);
+  assert_eq!(B1000::VALUE, 1000);
+  assert_eq!(B1024::VALUE, 1024);
+}
+
+#[test]
+fn test_binary_arithmetic()
+{
+  assert_same::<Succ<B3>, B0<B0<B1<U0>>>>();
+  assert_same::<Pred<B0<B0<B1<U0>>>>, B3>();
+  assert_same::<Pred<B1<U0>>, U0>();
+
+  assert_same::<Plus<U0, B5>, B5>();
+  assert_same::<Plus<B2, B3>, B5>();
+  assert_same::<Plus<B1000, B24>, B1024>();
+
+  assert_same::<Minus<B5, B2>, B3>();
+  assert_same::<Minus<B5, B5>, U0>();
+  assert_same::<Minus<B3, B2>, B1<U0>>();
+  assert_same::<Minus<B1024, B24>, B1000>();
+
+  assert_same::<SaturatingMinus<B5, U0>, B5>();
+  assert_same::<SaturatingMinus<B5, B2>, B3>();
+  assert_same::<SaturatingMinus<B5, B5>, U0>();
+  assert_same::<SaturatingMinus<B2, B5>, U0>();
+  assert_same::<SaturatingMinus<U0, B3>, U0>();
+  assert_same::<SaturatingMinus<B1024, B24>, B1000>();
+
+  assert_same::<Times<B3, U0>, U0>();
+  assert_same::<Times<B2, B3>, B0<B3>>();
+  assert_eq!(<Times<B1000, B24>>::VALUE, 24000);
+  assert_eq!(<Times<B1024, B1024>>::VALUE, 1 << 20);
+
+  assert_same::<Minimum<U0, B5>, U0>();
+  assert_same::<Minimum<B2, B5>, B2>();
+  assert_same::<Minimum<B5, B2>, B2>();
+  assert_same::<Minimum<B3, B3>, B3>();
+  assert_same::<Minimum<B1024, B1000>, B1000>();
+  assert_same::<Maximum<U0, B5>, B5>();
+  assert_same::<Maximum<B2, B5>, B5>();
+  assert_same::<Maximum<B5, B2>, B5>();
+  assert_same::<Maximum<B3, B3>, B3>();
+  assert_same::<Maximum<B1024, B1000>, B1024>();
+}
+
+#[test]
+fn test_binary_compare()
+{
+  assert_same::<Compared<B2, B5>, Lt>();
+  assert_same::<Compared<B5, B5>, Eq>();
+  assert_same::<Compared<B3, B2>, Gt>();
+  assert_same::<Compared<B2, B3>, Lt>();
+  assert_same::<Compared<B1024, B1000>, Gt>();
+
+  assert_eq!(ordering::<U0, B1<U0>>(), Ordering::Less);
+  assert_eq!(ordering::<B1000, B1024>(), Ordering::Less);
+}
+
+#[test]
+fn test_binary_peano()
+{
+  assert_same::<Peano<U0>, N0>();
+  assert_same::<Peano<B5>, N5>();
+  assert_same::<Binary<N6>, B0<B3>>();
+  assert_same::<Binary<Peano<B24>>, B24>();
+}
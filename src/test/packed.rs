@@ -0,0 +1,47 @@
+use crate::{
+  packed::*,
+  poly_fn,
+  product::*,
+  row::*,
+  type_app::*,
+};
+
+type Row4 = Cons<u64, Top>;
+type Row3 = Cons<u32, Row4>;
+type Row2 = Cons<u16, Row3>;
+type Row = Cons<u8, Row2>;
+
+fn packed_row<'a>() -> AppRow<'a, Packed<Row>, OptionF>
+{
+  wrap_row::<Packed<Row>, OptionF>(Cons(
+    Some(1),
+    Cons(Some(2), Cons(Some(3), Cons(Some(4), Top))),
+  ))
+}
+
+#[test]
+fn test_packed_row_roundtrip()
+{
+  let row = unpack_row(packed_row());
+  let Cons(a, tail) = *row.get_applied();
+  let Cons(b, _) = *tail.get_applied();
+
+  assert_eq!(a.get_applied(), Some(1));
+  assert_eq!(b.get_applied(), Some(2));
+}
+
+#[test]
+fn test_lift_packed_row()
+{
+  poly_fn! {
+    struct ToVec<X>(fx: App<OptionF, X>) -> App<VecF, X> {
+      wrap_app(fx.get_applied().into_iter().collect())
+    }
+  }
+
+  let row: AppRow<Packed<Row>, VecF> =
+    lift_packed::<Row, OptionF, VecF, _>(&ToVec, packed_row());
+
+  let Cons(a, Cons(b, Cons(c, Cons(d, Top)))) = *row.get_applied();
+  assert_eq!((a, b, c, d), (vec![1], vec![2], vec![3], vec![4]));
+}
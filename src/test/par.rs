@@ -0,0 +1,179 @@
+use std::{
+  collections::HashSet,
+  sync::Mutex,
+  thread,
+};
+
+use crate::{
+  function::*,
+  par::*,
+  type_app::*,
+};
+
+fn available_threads() -> usize
+{
+  thread::available_parallelism().map_or(1, |threads| threads.get())
+}
+
+#[test]
+fn test_par_fmap_preserves_order()
+{
+  let xs: App<VecF, u64> = wrap_app((0..1000).collect());
+  let config = ParConfig::default().with_chunk_size(7);
+
+  let ys = VecF::par_fmap(xs, wrap_send_function(|x| x * 2), config);
+
+  assert_eq!(ys.get_applied(), (0..1000).map(|x| x * 2).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_par_fmap_threads()
+{
+  let threads = Mutex::new(HashSet::new());
+  let xs: App<VecF, u8> = wrap_app(vec![0; 8]);
+
+  VecF::par_fmap(
+    xs,
+    wrap_send_function(|x| {
+      threads.lock().unwrap().insert(thread::current().id());
+      x
+    }),
+    ParConfig::default().with_chunk_size(2),
+  );
+
+  let threads = threads.into_inner().unwrap();
+  assert!(!threads.is_empty());
+  assert!(threads.len() <= available_threads().min(4));
+  assert!(!threads.contains(&thread::current().id()));
+}
+
+#[test]
+fn test_par_fmap_many_chunks()
+{
+  let threads = Mutex::new(HashSet::new());
+  let xs: App<VecF, u64> = wrap_app((0..200_000).collect());
+
+  let ys = VecF::par_fmap(
+    xs,
+    wrap_send_function(|x| {
+      threads.lock().unwrap().insert(thread::current().id());
+      x + 1
+    }),
+    ParConfig::default().with_chunk_size(1),
+  );
+
+  assert_eq!(ys.get_applied(), (1..200_001).collect::<Vec<_>>());
+  assert!(threads.into_inner().unwrap().len() <= available_threads());
+
+  let sum = VecF::par_fold(
+    wrap_app((0..200_000u64).collect()),
+    0,
+    wrap_send_function(|(acc, x)| acc + x),
+    wrap_send_function(|(acc1, acc2)| acc1 + acc2),
+    ParConfig::default().with_chunk_size(1),
+  );
+
+  assert_eq!(sum, 19_999_900_000);
+}
+
+#[test]
+fn test_par_fmap_sequential_fallback()
+{
+  let current = thread::current().id();
+
+  for config in [
+    ParConfig::sequential().with_chunk_size(1),
+    ParConfig::default().with_chunk_size(1).with_min_len(10),
+    ParConfig::default().with_chunk_size(100),
+  ] {
+    let xs: App<VecF, u8> = wrap_app(vec![1, 2, 3]);
+    let ys = VecF::par_fmap(
+      xs,
+      wrap_send_function(|x| {
+        assert_eq!(thread::current().id(), current);
+        x + 1
+      }),
+      config,
+    );
+
+    assert_eq!(ys.get_applied(), vec![2, 3, 4]);
+  }
+}
+
+#[test]
+fn test_par_fold()
+{
+  let xs: App<VecF, u32> = wrap_app((0..26).collect());
+
+  // String concatenation is associative but not commutative, so this
+  // checks that the chunks are combined in order.
+  let res = VecF::par_fold(
+    xs,
+    String::new(),
+    wrap_send_function(|(mut acc, x): (String, u32)| {
+      acc.push((b'a' + x as u8) as char);
+      acc
+    }),
+    wrap_send_function(|(acc1, acc2): (String, String)| acc1 + &acc2),
+    ParConfig::default().with_chunk_size(4),
+  );
+
+  assert_eq!(res, "abcdefghijklmnopqrstuvwxyz");
+}
+
+#[test]
+fn test_par_compose()
+{
+  type OptionVecF = ComposeApp<OptionF, VecF>;
+
+  let config = ParConfig::default().with_chunk_size(2);
+  let xs: App<OptionVecF, u32> = wrap_app(wrap_app(Some(wrap_app(vec![
+    1, 2, 3, 4, 5,
+  ]))));
+
+  let ys = OptionVecF::par_fmap(xs, wrap_send_function(|x| x * 10), config);
+  let sum = OptionVecF::par_fold(
+    ys,
+    0,
+    wrap_send_function(|(acc, y)| acc + y),
+    wrap_send_function(|(acc1, acc2)| acc1 + acc2),
+    config,
+  );
+
+  assert_eq!(sum, 150);
+
+  let vs: App<ComposeApp<VecF, VecF>, u32> = wrap_app(wrap_app(vec![
+    wrap_app(vec![1, 2, 3]),
+    wrap_app(vec![]),
+    wrap_app(vec![4, 5]),
+  ]));
+
+  let ws: Vec<_> = ComposeApp::<VecF, VecF>::par_fmap(
+    vs,
+    wrap_send_function(|x| x + 1),
+    config,
+  )
+  .get_applied()
+  .get_applied()
+  .into_iter()
+  .map(|w| w.get_applied())
+  .collect();
+
+  assert_eq!(ws, vec![vec![2, 3, 4], vec![], vec![5, 6]]);
+}
+
+#[test]
+#[should_panic(expected = "odd element")]
+fn test_par_fmap_panic()
+{
+  let xs: App<VecF, u8> = wrap_app(vec![2, 4, 5, 6]);
+
+  VecF::par_fmap(
+    xs,
+    wrap_send_function(|x| {
+      assert!(x % 2 == 0, "odd element");
+      x
+    }),
+    ParConfig::default().with_chunk_size(1),
+  );
+}
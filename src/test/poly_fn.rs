@@ -0,0 +1,144 @@
+use std::{
+  rc::Rc,
+  sync::Arc,
+};
+
+use crate::{
+  poly_fn,
+  poly_fn::*,
+  product::*,
+  row::*,
+  sum::*,
+  type_app::*,
+};
+
+poly_fn! {
+  struct Empty<X>() -> App<VecF, X> {
+    wrap_app(Vec::new())
+  }
+}
+
+poly_fn! {
+  struct Head<X>(xs: App<VecF, X>) -> App<OptionF, X> {
+    wrap_app(xs.get_applied().into_iter().next())
+  }
+}
+
+poly_fn! {
+  struct ToVec<X>(x: App<OptionF, X>) -> App<VecF, X> {
+    wrap_app(x.get_applied().into_iter().collect())
+  }
+}
+
+poly_fn! {
+  struct Reverse<X>(xs: App<VecF, X>) -> App<VecF, X> {
+    let mut xs = xs.get_applied();
+    xs.reverse();
+    wrap_app(xs)
+  }
+}
+
+/// A polymorphic function stored together with other data.
+struct Pipeline<P>
+{
+  name: &'static str,
+  step: P,
+}
+
+fn run<'a, X: 'a, P>(
+  step: &P,
+  xs: Vec<X>,
+) -> Vec<X>
+where
+  P: PolyFn<VecF, VecF>,
+{
+  step.call(wrap_app(xs)).get_applied()
+}
+
+#[test]
+fn test_forall()
+{
+  let xs: Vec<u32> = Empty.instantiate().get_applied();
+  let ys: Vec<String> = Empty.instantiate().get_applied();
+  assert!(xs.is_empty());
+  assert!(ys.is_empty());
+
+  let boxed: Box<Empty> = Box::new(Empty);
+  let zs: Vec<bool> = boxed.instantiate().get_applied();
+  assert!(zs.is_empty());
+}
+
+#[test]
+fn test_poly_fn_instantiations()
+{
+  assert_eq!(Head.call(wrap_app(vec![1, 2])).get_applied(), Some(1));
+  assert_eq!(Head.call(wrap_app(vec!["a"])).get_applied(), Some("a"));
+  assert_eq!(Head.call(wrap_app(Vec::<()>::new())).get_applied(), None);
+}
+
+#[test]
+fn test_poly_fn_compose()
+{
+  let first = Head.then(ToVec);
+  assert_eq!(first.call(wrap_app(vec![1, 2, 3])).get_applied(), vec![1]);
+
+  let last = Reverse.then(Head).then(ToVec);
+  let copied = last;
+  assert_eq!(copied.call(wrap_app(vec![1, 2, 3])).get_applied(), vec![3]);
+
+  let same = PolyId.then(Reverse).then(PolyId);
+  assert_eq!(run(&same, vec!['a', 'b']), vec!['b', 'a']);
+}
+
+#[test]
+fn test_poly_fn_boxed()
+{
+  let pipeline = Pipeline {
+    name: "reverse twice",
+    step: Box::new(Reverse.then(Reverse)),
+  };
+
+  assert_eq!(pipeline.name, "reverse twice");
+  assert_eq!(run(&pipeline.step, vec![1, 2, 3]), vec![1, 2, 3]);
+
+  let shared = Rc::new(Reverse);
+  let cloned = Rc::clone(&shared);
+  assert_eq!(run(&cloned, vec![1, 2]), vec![2, 1]);
+
+  let step = Arc::new(Reverse);
+  let steps = [Arc::clone(&step), Arc::clone(&step), step];
+  let res = steps.iter().fold(vec![1, 2], |xs, step| run(step, xs));
+  assert_eq!(res, vec![2, 1]);
+}
+
+#[test]
+fn test_poly_fn_lift_row()
+{
+  type Person = Cons<String, Cons<u32, Top>>;
+  type Shape = Union<u32, Union<String, Bottom>>;
+
+  let tail = wrap_row::<Cons<u32, Top>, VecF>(Cons(
+    wrap_app(vec![42, 7]),
+    wrap_row::<Top, VecF>(Top),
+  ));
+  let row = wrap_row::<Person, VecF>(Cons(wrap_app(vec![]), tail));
+
+  let row = Person::lift(&Head.then(ToVec), row);
+
+  let Cons(name, tail) = *row.get_applied();
+  let Cons(age, _) = *tail.get_applied();
+  assert!(name.get_applied().is_empty());
+  assert_eq!(age.get_applied(), vec![42]);
+
+  let names = wrap_app(vec!["a".to_string(), "b".to_string()]);
+  let tail = wrap_row::<Union<String, Bottom>, VecF>(Inl(names));
+  let row = wrap_row::<Shape, VecF>(Inr(tail));
+
+  match *Shape::lift(&Head, row).get_applied() {
+    Inr(tail) => match *tail.get_applied() {
+      Inl(name) => assert_eq!(name.get_applied(), Some("a".to_string())),
+      Inr(_) => panic!("expected the second variant"),
+    },
+    Inl(_) => panic!("expected the second variant"),
+  }
+}
@@ -0,0 +1,109 @@
+use crate::{
+  function::*,
+  product::*,
+  refl::*,
+  row::*,
+  type_app::*,
+};
+
+fn cast_vec<T1, T2>(xs: Vec<T1>) -> Vec<T2>
+where
+  T1: Refl<Refl = T2>,
+{
+  TypeEq::from_witness(vec_congruence::<T1, T2>()).cast(xs)
+}
+
+fn cast_app<'a, F, T1: 'a, T2: 'a>(fx: Applied<'a, F, T1>) -> Applied<'a, F, T2>
+where
+  T1: Refl<Refl = T2>,
+  F: TypeApp<'a, T1> + TypeApp<'a, T2>,
+  Applied<'a, F, T1>: Sized,
+  Applied<'a, F, T2>: Sized,
+{
+  TypeEq::from_witness(app_congruence::<F, T1, T2>()).cast(fx)
+}
+
+fn cast_con<'a, F1, F2, X: 'a>(fx: Applied<'a, F1, X>) -> Applied<'a, F2, X>
+where
+  F1: Refl<Refl = F2> + TypeApp<'a, X>,
+  F2: TypeApp<'a, X>,
+  Applied<'a, F1, X>: Sized,
+  Applied<'a, F2, X>: Sized,
+{
+  TypeEq::from_witness(con_congruence::<F1, F2, X>()).cast(fx)
+}
+
+fn call_cast<A1, A2, B1, B2>(
+  f: &dyn Fn(A1) -> B1,
+  a: A2,
+) -> B2
+where
+  A1: Refl<Refl = A2>,
+  B1: Refl<Refl = B2>,
+{
+  let left = bi_app_congruence_left::<FunctionF, A1, A2, B1>();
+  let right = bi_app_congruence_right::<FunctionF, A2, B1, B2>();
+
+  let f = TypeEq::from_witness(refl_trans(left, right)).cast_ref(f);
+  f(a)
+}
+
+fn cast_row<'a, Row1, Row2>(
+  row: <Row1 as RowApp<'a, OptionF>>::Applied
+) -> <Row2 as RowApp<'a, OptionF>>::Applied
+where
+  Row1: Refl<Refl = Row2> + RowApp<'a, OptionF>,
+  Row2: RowApp<'a, OptionF>,
+  <Row1 as RowApp<'a, OptionF>>::Applied: Sized,
+  <Row2 as RowApp<'a, OptionF>>::Applied: Sized,
+{
+  TypeEq::from_witness(row_congruence::<OptionF, Row1, Row2>()).cast(row)
+}
+
+fn cast_compose<'a, F1, F2, G1, G2, X: 'a>(
+  fx: Applied<'a, Compose<F1, G1>, X>
+) -> Applied<'a, Compose<F2, G2>, X>
+where
+  F1: Refl<Refl = F2>,
+  G1: Refl<Refl = G2>,
+  Compose<F1, G1>: TypeApp<'a, X>,
+  Compose<F2, G1>: TypeApp<'a, X>,
+  Compose<F2, G2>: TypeApp<'a, X>,
+  Applied<'a, Compose<F1, G1>, X>: Sized,
+  Applied<'a, Compose<F2, G2>, X>: Sized,
+{
+  let eq = TypeEq::from_witness(compose_congruence::<F1, F2, G1, G2, X>());
+  eq.cast(fx)
+}
+
+#[test]
+fn test_app_congruence()
+{
+  assert_eq!(cast_vec::<u8, u8>(vec![1, 2]), vec![1, 2]);
+  assert_eq!(cast_app::<OptionF, u8, u8>(Some(1)), Some(1));
+  assert_eq!(cast_app::<ResultF<()>, u8, u8>(Ok(1)), Ok(1));
+  assert_eq!(cast_con::<VecF, VecF, u8>(vec![3]), vec![3]);
+}
+
+#[test]
+fn test_bi_app_congruence()
+{
+  let f = |x: u32| x.to_string();
+  assert_eq!(call_cast::<u32, u32, String, String>(&f, 42), "42");
+}
+
+#[test]
+fn test_row_congruence()
+{
+  let row = Cons(wrap_app(Some(1)), wrap_row::<Top, OptionF>(Top));
+  let Cons(x, _) = cast_row::<Cons<u8, Top>, Cons<u8, Top>>(row);
+
+  assert_eq!(x.get_applied(), Some(1));
+}
+
+#[test]
+fn test_compose_congruence()
+{
+  let xs = cast_compose::<VecF, VecF, OptionF, OptionF, u8>(vec![Some(1)]);
+  assert_eq!(xs, vec![Some(1)]);
+}
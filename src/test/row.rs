@@ -0,0 +1,104 @@
+use crate::{
+  function::*,
+  poly_fn,
+  product::*,
+  row::*,
+  sum::*,
+  type_app::*,
+};
+
+type Person = Cons<String, Cons<u32, Top>>;
+
+fn person_row<'a>(
+  name: Option<String>,
+  age: Option<u32>,
+) -> AppRow<'a, Person, OptionF>
+{
+  let tail = wrap_row::<Cons<u32, Top>, OptionF>(Cons(
+    wrap_app(age),
+    wrap_row::<Top, OptionF>(Top),
+  ));
+
+  wrap_row::<Person, OptionF>(Cons(wrap_app(name), tail))
+}
+
+#[test]
+fn test_sequence_row()
+{
+  let row = person_row(Some("Alice".to_string()), Some(42));
+
+  let res: Option<Person> =
+    <Person as SequenceRow<FunctionOnceF>>::sequence(row).get_applied();
+
+  let Cons(name, Cons(age, Top)) = res.unwrap();
+  assert_eq!(name, "Alice");
+  assert_eq!(age, 42);
+
+  let row = person_row(Some("Bob".to_string()), None);
+  let res: Option<Person> =
+    <Person as SequenceRow<FunctionF>>::sequence(row).get_applied();
+
+  assert!(res.is_none());
+}
+
+#[test]
+fn test_traverse_row()
+{
+  poly_fn! {
+    struct Head<X>(fx: App<VecF, X>) -> App<OptionF, X> {
+      wrap_app(fx.get_applied().into_iter().next())
+    }
+  }
+
+  fn vec_row<'a>(
+    names: Vec<String>,
+    ages: Vec<u32>,
+  ) -> AppRow<'a, Person, VecF>
+  {
+    let tail = wrap_row::<Cons<u32, Top>, VecF>(Cons(
+      wrap_app(ages),
+      wrap_row::<Top, VecF>(Top),
+    ));
+
+    wrap_row::<Person, VecF>(Cons(wrap_app(names), tail))
+  }
+
+  let row = vec_row(vec!["Alice".to_string(), "Bob".to_string()], vec![42]);
+  let res: Option<Person> =
+    <Person as TraverseRow<FunctionOnceF>>::traverse(&Head, row)
+      .get_applied();
+
+  let Cons(name, Cons(age, Top)) = res.unwrap();
+  assert_eq!(name, "Alice");
+  assert_eq!(age, 42);
+
+  let row = vec_row(vec!["Alice".to_string()], vec![]);
+  let res: Option<Person> =
+    <Person as TraverseRow<FunctionOnceF>>::traverse(&Head, row)
+      .get_applied();
+
+  assert!(res.is_none());
+}
+
+#[test]
+fn test_distribute_row()
+{
+  type Shape = Union<u32, Union<String, Bottom>>;
+
+  let names = wrap_app(vec!["a".to_string(), "b".to_string()]);
+  let tail = wrap_row::<Union<String, Bottom>, VecF>(Inl(names));
+  let row = wrap_row::<Shape, VecF>(Inr(tail));
+
+  let res: Vec<Shape> =
+    <Shape as Distribute<FunctionMutF>>::distribute(row).get_applied();
+
+  let names: Vec<String> = res
+    .into_iter()
+    .map(|shape| match shape {
+      Inr(Inl(name)) => name,
+      _ => panic!("expected the second variant"),
+    })
+    .collect();
+
+  assert_eq!(names, vec!["a".to_string(), "b".to_string()]);
+}
@@ -0,0 +1,128 @@
+use std::thread;
+
+use crate::{
+  bi_type_app::*,
+  function::*,
+  functor::*,
+  packed::*,
+  product::*,
+  row::{
+    wrap_row_send,
+    SendAppRow,
+  },
+  type_app::*,
+};
+
+type Row = Cons<u8, Cons<bool, Top>>;
+
+#[test]
+fn test_send_app_spawn()
+{
+  let xs: SendApp<VecF, u32> = wrap_app_send(vec![1, 2, 3]);
+
+  let sum = thread::spawn(move || {
+    let ys = VecF::fmap(xs.into_app(), wrap_send_function(|x| x * 2));
+    VecF::fold_left(ys, 0, wrap_send_function(|(acc, y)| acc + y))
+  })
+  .join()
+  .unwrap();
+
+  assert_eq!(sum, 12);
+}
+
+#[test]
+fn test_sync_app_scoped()
+{
+  let xs: SyncApp<VecF, u32> = wrap_app_sync(vec![1, 2, 3, 4]);
+  let offset = 10;
+
+  let sums = thread::scope(|scope| {
+    let handles: Vec<_> = (0..2)
+      .map(|i| {
+        let xs = &xs;
+        scope.spawn(move || {
+          xs.get_applied_borrow().iter().skip(i * 2).take(2).sum::<u32>()
+            + offset
+        })
+      })
+      .collect();
+
+    handles
+      .into_iter()
+      .map(|handle| handle.join().unwrap())
+      .collect::<Vec<_>>()
+  });
+
+  assert_eq!(sums, vec![13, 17]);
+  assert_eq!(App::from(xs).get_applied(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn test_send_closures()
+{
+  let mut count = 0;
+
+  thread::scope(|scope| {
+    scope.spawn(|| {
+      let xs: App<OptionF, u8> = wrap_app(Some(1));
+      let ys = OptionF::fmap(xs, wrap_send_function_mut(|x| {
+        count += 1;
+        x + 1
+      }));
+      assert_eq!(ys.get_applied(), Some(2));
+    });
+  });
+
+  assert_eq!(count, 1);
+
+  let name = "x".to_string();
+  let f = wrap_send_function_once(move |x: u8| format!("{}{}", name, x));
+  let res = thread::spawn(move || {
+    let xs: App<Identity, u8> = wrap_app(1);
+    Identity::fmap(xs, f).get_applied()
+  })
+  .join()
+  .unwrap();
+
+  assert_eq!(res, "x1");
+}
+
+#[test]
+fn test_send_compose_fmap()
+{
+  let xs: App<ComposeApp<VecF, OptionF>, u8> =
+    wrap_app(wrap_app(vec![wrap_app(Some(1)), wrap_app(None)]));
+
+  let ys = ComposeApp::<VecF, OptionF>::fmap(
+    xs,
+    wrap_send_function(|x: u8| x + 1),
+  );
+
+  let ys: Vec<_> = ys
+    .get_applied()
+    .get_applied()
+    .into_iter()
+    .map(|y| y.get_applied())
+    .collect();
+
+  assert_eq!(ys, vec![Some(2), None]);
+}
+
+#[test]
+fn test_send_bi_app_and_row()
+{
+  let f: SendBiApp<SendFunctionOnceF, u8, u8> =
+    wrap_send_function_once(|x| x + 1);
+  let row: SendAppRow<Packed<Row>, OptionF> =
+    wrap_row_send(Cons(Some(1), Cons(Some(true), Top)));
+
+  let (y, row) = thread::spawn(move || {
+    let f: BiApp<SendFunctionOnceF, u8, u8> = f;
+    (SendFunctionOnceF::apply_once(f, 1), row)
+  })
+  .join()
+  .unwrap();
+
+  let Cons(a, Cons(b, Top)) = *row.get_applied();
+  assert_eq!((y, a, b), (2, Some(1), Some(true)));
+}
@@ -0,0 +1,231 @@
+use std::{
+  any::Any,
+  collections::HashMap,
+};
+
+use crate::{
+  function::*,
+  functor::*,
+  refl::{
+    try_cast,
+    try_refl,
+    TypeEq,
+  },
+  type_app::*,
+};
+
+/// A typed expression language, with every variant that fixes the
+/// result type `T` carrying a proof of what `T` is.
+enum Expr<T>
+{
+  Int(TypeEq<T, i64>, i64),
+  Bool(TypeEq<T, bool>, bool),
+  Add(TypeEq<T, i64>, Box<Expr<i64>>, Box<Expr<i64>>),
+  Equal(TypeEq<T, bool>, Box<Expr<i64>>, Box<Expr<i64>>),
+  Not(TypeEq<T, bool>, Box<Expr<bool>>),
+  If(Box<Expr<bool>>, Box<Expr<T>>, Box<Expr<T>>),
+}
+
+fn int(x: i64) -> Expr<i64>
+{
+  Expr::Int(TypeEq::refl(), x)
+}
+
+fn boolean(x: bool) -> Expr<bool>
+{
+  Expr::Bool(TypeEq::refl(), x)
+}
+
+fn add(
+  x: Expr<i64>,
+  y: Expr<i64>,
+) -> Expr<i64>
+{
+  Expr::Add(TypeEq::refl(), Box::new(x), Box::new(y))
+}
+
+fn equal(
+  x: Expr<i64>,
+  y: Expr<i64>,
+) -> Expr<bool>
+{
+  Expr::Equal(TypeEq::refl(), Box::new(x), Box::new(y))
+}
+
+fn not(x: Expr<bool>) -> Expr<bool>
+{
+  Expr::Not(TypeEq::refl(), Box::new(x))
+}
+
+fn if_then_else<T>(
+  cond: Expr<bool>,
+  then: Expr<T>,
+  other: Expr<T>,
+) -> Expr<T>
+{
+  Expr::If(Box::new(cond), Box::new(then), Box::new(other))
+}
+
+fn eval<T>(expr: &Expr<T>) -> T
+{
+  match expr {
+    Expr::Int(eq, x) => eq.symm().cast(*x),
+    Expr::Bool(eq, x) => eq.symm().cast(*x),
+    Expr::Add(eq, x, y) => eq.symm().cast(eval(x) + eval(y)),
+    Expr::Equal(eq, x, y) => eq.symm().cast(eval(x) == eval(y)),
+    Expr::Not(eq, x) => eq.symm().cast(!eval(x)),
+    Expr::If(cond, then, other) => {
+      if eval(cond) {
+        eval(then)
+      } else {
+        eval(other)
+      }
+    }
+  }
+}
+
+/// Constant folding preserves the type index of an expression.
+fn simplify<T>(expr: Expr<T>) -> Expr<T>
+{
+  match expr {
+    Expr::Add(eq, x, y) => match (simplify(*x), simplify(*y)) {
+      (Expr::Int(_, x), Expr::Int(_, y)) => Expr::Int(eq, x + y),
+      (x, y) => Expr::Add(eq, Box::new(x), Box::new(y)),
+    },
+    Expr::If(cond, then, other) => match simplify(*cond) {
+      Expr::Bool(_, true) => simplify(*then),
+      Expr::Bool(_, false) => simplify(*other),
+      cond => Expr::If(Box::new(cond), then, other),
+    },
+    expr => expr,
+  }
+}
+
+#[test]
+fn test_gadt_eval()
+{
+  let expr = if_then_else(
+    not(equal(add(int(1), int(2)), int(4))),
+    add(int(10), int(20)),
+    int(0),
+  );
+
+  assert_eq!(eval(&expr), 30);
+  assert!(eval(&equal(int(3), add(int(1), int(2)))));
+  assert!(!eval(&not(boolean(true))));
+}
+
+#[test]
+fn test_gadt_simplify()
+{
+  let expr = simplify(if_then_else(
+    boolean(false),
+    int(1),
+    add(int(2), add(int(3), int(4))),
+  ));
+
+  match &expr {
+    Expr::Int(_, x) => assert_eq!(*x, 9),
+    _ => panic!("expected the expression to be folded"),
+  }
+
+  assert_eq!(eval(&expr), 9);
+}
+
+fn same<A, B>(
+  eq: TypeEq<A, B>,
+  a: A,
+  b: B,
+) -> bool
+where
+  B: PartialEq,
+{
+  eq.cast(a) == b
+}
+
+#[test]
+fn test_type_eq_casts()
+{
+  let eq: TypeEq<String, String> = TypeEq::refl();
+
+  let mut s = "hello".to_string();
+  assert_eq!(eq.cast_ref(&s), "hello");
+
+  eq.cast_mut(&mut s).push_str(" world");
+  assert_eq!(eq.symm().cast(s), "hello world");
+
+  let eq: TypeEq<str, str> = TypeEq::refl();
+  assert_eq!(eq.cast_ref("unsized"), "unsized");
+
+  let eq: TypeEq<u8, u8> = TypeEq::from_refl();
+  assert!(same(eq.trans(eq.symm()), 1, 1));
+}
+
+#[test]
+fn test_type_eq_lift()
+{
+  fn first<T, U>(
+    eq: TypeEq<T, U>,
+    xs: Vec<T>,
+  ) -> Option<U>
+  {
+    let eq = eq.lift::<OptionF>();
+    eq.cast(xs.into_iter().next())
+  }
+
+  let eq: TypeEq<u32, u32> = TypeEq::refl();
+  assert_eq!(first(eq, vec![1, 2]), Some(1));
+
+  let xs: App<VecF, u32> = wrap_app(vec![1, 2, 3]);
+  let ys = eq.lift::<AppF<VecF>>().cast(xs);
+  assert_eq!(ys.get_applied(), vec![1, 2, 3]);
+}
+
+fn describe<T: 'static>(x: &T) -> String
+{
+  if let Some(eq) = try_refl::<T, u32>() {
+    format!("u32 {}", eq.cast_ref(x))
+  } else if let Some(eq) = try_refl::<T, String>() {
+    format!("string {:?}", eq.cast_ref(x))
+  } else {
+    "unknown".to_string()
+  }
+}
+
+#[test]
+fn test_try_refl()
+{
+  assert_eq!(describe(&42u32), "u32 42");
+  assert_eq!(describe(&"hi".to_string()), "string \"hi\"");
+  assert_eq!(describe(&42u64), "unknown");
+
+  assert!(try_refl::<str, str>().is_some());
+  assert!(try_refl::<[u8], [u16]>().is_none());
+  assert!(try_refl::<Vec<u8>, Vec<u8>>().is_some());
+
+  assert_eq!(try_cast::<u8, u8>(3), Ok(3));
+  assert_eq!(try_cast::<u8, i8>(3), Err(3));
+}
+
+#[test]
+fn test_downcast_app()
+{
+  // A plugin registry that only sees type-erased values.
+  let mut registry: HashMap<&str, Box<dyn Any>> = HashMap::new();
+  registry.insert("ports", Box::new(vec![80u16, 443]));
+  registry.insert("name", Box::new(Some("lambek".to_string())));
+
+  let ports = registry.remove("ports").unwrap();
+  let ports = match downcast_app::<OptionF, u16>(ports) {
+    Ok(_) => panic!("expected the ports to not be an Option"),
+    Err(ports) => ports,
+  };
+  let ports: App<VecF, u16> = downcast_app(ports).ok().unwrap();
+
+  let ports = VecF::fmap(ports, wrap_function(|port: u16| port + 8000));
+  assert_eq!(ports.get_applied(), vec![8080, 8443]);
+
+  let name = registry.remove("name").unwrap();
+  let name = downcast_app::<OptionF, String>(name).ok().unwrap();
+  assert_eq!(name.get_applied(), Some("lambek".to_string()));
+}
@@ -0,0 +1,37 @@
+use crate::{
+  test::assert_same,
+  units::*,
+};
+
+#[test]
+fn test_dims_arithmetic()
+{
+  assert_same::<Multiplied<Length, Length>, Area>();
+  assert_same::<Multiplied<Area, Length>, Volume>();
+  assert_same::<Divided<Length, Time>, Velocity>();
+  assert_same::<Divided<Velocity, Time>, Acceleration>();
+  assert_same::<Multiplied<Mass, Acceleration>, Force>();
+  assert_same::<Multiplied<Force, Length>, Energy>();
+  assert_same::<Divided<Length, Length>, Dimensionless>();
+  assert_same::<Divided<Dimensionless, Time>, Frequency>();
+}
+
+#[test]
+fn test_quantity_operations()
+{
+  let width: Quantity<f64, Length> = Quantity::new(3.0);
+  let height: Quantity<f64, Length> = Quantity::new(4.0);
+
+  let area: Quantity<f64, Area> = width * height;
+  assert_eq!(*area.value(), 12.0);
+
+  let perimeter = (width + height) * Quantity::<_, Dimensionless>::new(2.0);
+  assert_eq!(perimeter, Quantity::<f64, Length>::new(14.0));
+  assert!(width < height);
+  assert_eq!((-(height - width)).into_value(), -1.0);
+
+  let mass: Quantity<f64, Mass> = Quantity::new(2.0);
+  let time: Quantity<f64, Time> = Quantity::new(2.0);
+  let energy: Quantity<f64, Energy> = mass * area / (time * time);
+  assert_eq!(energy.into_value(), 6.0);
+}
@@ -0,0 +1,63 @@
+use crate::{
+  function::*,
+  functor::*,
+  nat::*,
+  type_app::*,
+  vect::*,
+};
+
+type N2 = S<S<Z>>;
+type N3 = S<N2>;
+
+#[test]
+fn test_vect_operations()
+{
+  let xs: Vect<N2, u32> = Vect::new().push(1).push(2);
+  let ys: Vect<S<Z>, u32> = Vect::new().push(3);
+
+  let zs: Vect<N3, u32> = xs.append(ys);
+  assert_eq!(zs.len(), 3);
+  assert_eq!(zs.as_slice(), &[1, 2, 3]);
+  assert_eq!(*zs.head(), 1);
+  assert_eq!(*zs.last(), 3);
+
+  let (x, rest) = zs.clone().uncons();
+  assert_eq!(x, 1);
+  assert_eq!(rest.into_vec(), vec![2, 3]);
+
+  let (rest, x) = zs.clone().pop();
+  assert_eq!(x, 3);
+  assert_eq!(rest.tail().into_vec(), vec![2]);
+
+  let i: Fin<N3> = Fin::zero().succ().succ();
+  assert_eq!(zs[i], 3);
+  assert_eq!(zs[Fin::zero().weaken().weaken()], 1);
+  assert!(Fin::<N3>::new(3).is_none());
+  assert_eq!(zs[Fin::new(1).unwrap()], 2);
+
+  assert!(Vect::<N2, u32>::from_vec(vec![1, 2, 3]).is_none());
+  let ws = Vect::<N3, &str>::from_vec(vec!["a", "b", "c"]).unwrap();
+
+  let pairs = zs.zip(ws);
+  assert_eq!(pairs.into_vec(), vec![(1, "a"), (2, "b"), (3, "c")]);
+}
+
+#[test]
+fn test_vect_functor()
+{
+  let xs: App<VectF<N3>, u32> = wrap_app(Vect::new().push(1).push(2).push(3));
+
+  let ys = VectF::fmap(xs, wrap_function(|x: u32| x * 10));
+  let total = VectF::fold_left(ys, 0, wrap_function_mut(|(b, a)| b + a));
+  assert_eq!(total, 60);
+
+  let xs: App<VectF<N3>, u32> = wrap_app(Vect::new().push(1).push(2).push(3));
+  let res: Option<Vect<N3, u32>> = VectF::traverse::<OptionF, _, _>(
+    xs,
+    wrap_function_mut(|x: u32| wrap_app(x.checked_sub(1))),
+  )
+  .get_applied()
+  .map(App::get_applied);
+
+  assert_eq!(res.unwrap().into_vec(), vec![0, 1, 2]);
+}
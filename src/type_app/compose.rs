@@ -6,21 +6,146 @@ use super::{
     TypeCon,
   },
   dynamic::App,
+  generic::{
+    TypeAppCont,
+    TypeAppContSized,
+    TypeAppGeneric,
+    TypeAppGenericSized,
+    TypeAppGenericUnsized,
+  },
 };
 
 pub struct Compose<F: ?Sized, G: ?Sized>(PhantomData<F>, PhantomData<G>);
 
 impl<F: ?Sized, G: ?Sized> TypeCon for Compose<F, G> {}
 
-impl<'a, F: 'a + ?Sized, G: 'a + ?Sized, X: 'a + ?Sized, FX: 'a, GX: 'a>
+impl<'a, F: 'a + ?Sized, G: 'a + ?Sized, X: 'a + ?Sized, FX, GX>
   TypeApp<'a, X> for Compose<F, G>
 where
+  FX: 'a + ?Sized,
+  GX: 'a + ?Sized,
   G: TypeApp<'a, X, Applied = GX>,
   F: TypeApp<'a, GX, Applied = FX>,
 {
   type Applied = FX;
 }
 
+/// The outer type constructor `F` is applied to `Applied<G, X>`, so
+/// the inner type constructor `G` has to produce sized applied types
+/// for `F` to accept them as type arguments. When the applied types of
+/// `G` may be unsized, use [ComposeApp] instead, which wraps them in
+/// an [App].
+impl<F, G> TypeAppGeneric for Compose<F, G>
+where
+  F: TypeAppGeneric,
+  G: TypeAppGenericSized,
+{
+  fn with_type_app<'a, X: 'a, R: 'a, Cont: 'a>(cont: Cont) -> R
+  where
+    Self: 'a,
+    Cont: TypeAppCont<'a, Self, X, R>,
+  {
+    struct InnerCont<Cont, F: ?Sized>(Cont, PhantomData<F>);
+
+    struct OuterCont<Cont, G: ?Sized, X: ?Sized>(
+      Cont,
+      PhantomData<G>,
+      PhantomData<X>,
+    );
+
+    impl<'a, F: 'a, G: 'a, X: 'a, R: 'a, Cont: 'a>
+      TypeAppContSized<'a, G, X, R> for InnerCont<Cont, F>
+    where
+      F: TypeAppGeneric,
+      Cont: TypeAppCont<'a, Compose<F, G>, X, R>,
+    {
+      fn on_sized_type_app(self) -> R
+      where
+        G: TypeApp<'a, X>,
+        G::Applied: Sized,
+      {
+        F::with_type_app::<'a, G::Applied, R, _>(OuterCont(
+          self.0,
+          PhantomData::<G>,
+          PhantomData::<X>,
+        ))
+      }
+    }
+
+    impl<'a, F: 'a, G: 'a, X: 'a, GX: 'a, R: 'a, Cont: 'a>
+      TypeAppCont<'a, F, GX, R> for OuterCont<Cont, G, X>
+    where
+      G: TypeApp<'a, X, Applied = GX>,
+      Cont: TypeAppCont<'a, Compose<F, G>, X, R>,
+    {
+      fn on_type_app(self) -> R
+      where
+        F: TypeApp<'a, GX>,
+      {
+        self.0.on_type_app()
+      }
+    }
+
+    G::with_sized_type_app::<'a, X, R, _>(InnerCont(cont, PhantomData::<F>))
+  }
+}
+
+impl<F, G> TypeAppGenericSized for Compose<F, G>
+where
+  F: TypeAppGenericSized,
+  G: TypeAppGenericSized,
+{
+  fn with_sized_type_app<'a, X: 'a, R: 'a, Cont: 'a>(cont: Cont) -> R
+  where
+    Self: 'a,
+    Cont: TypeAppContSized<'a, Self, X, R>,
+  {
+    struct InnerCont<Cont, F: ?Sized>(Cont, PhantomData<F>);
+
+    struct OuterCont<Cont, G: ?Sized, X: ?Sized>(
+      Cont,
+      PhantomData<G>,
+      PhantomData<X>,
+    );
+
+    impl<'a, F: 'a, G: 'a, X: 'a, R: 'a, Cont: 'a>
+      TypeAppContSized<'a, G, X, R> for InnerCont<Cont, F>
+    where
+      F: TypeAppGenericSized,
+      Cont: TypeAppContSized<'a, Compose<F, G>, X, R>,
+    {
+      fn on_sized_type_app(self) -> R
+      where
+        G: TypeApp<'a, X>,
+        G::Applied: Sized,
+      {
+        F::with_sized_type_app::<'a, G::Applied, R, _>(OuterCont(
+          self.0,
+          PhantomData::<G>,
+          PhantomData::<X>,
+        ))
+      }
+    }
+
+    impl<'a, F: 'a, G: 'a, X: 'a, GX: 'a, R: 'a, Cont: 'a>
+      TypeAppContSized<'a, F, GX, R> for OuterCont<Cont, G, X>
+    where
+      G: TypeApp<'a, X, Applied = GX>,
+      Cont: TypeAppContSized<'a, Compose<F, G>, X, R>,
+    {
+      fn on_sized_type_app(self) -> R
+      where
+        F: TypeApp<'a, GX>,
+        F::Applied: Sized,
+      {
+        self.0.on_sized_type_app()
+      }
+    }
+
+    G::with_sized_type_app::<'a, X, R, _>(InnerCont(cont, PhantomData::<F>))
+  }
+}
+
 pub struct ComposeApp<F: ?Sized, G: ?Sized>(PhantomData<F>, PhantomData<G>);
 
 impl<F: ?Sized, G: ?Sized> TypeCon for ComposeApp<F, G> {}
@@ -30,3 +155,25 @@ impl<'a, F: 'a + ?Sized, G: 'a + ?Sized, X: 'a + ?Sized> TypeApp<'a, X>
 {
   type Applied = App<'a, F, App<'a, G, X>>;
 }
+
+impl<F: ?Sized, G: ?Sized> TypeAppGenericUnsized for ComposeApp<F, G>
+{
+  fn with_type_app<'a, X: 'a + ?Sized, R: 'a, Cont: 'a>(cont: Cont) -> R
+  where
+    Self: 'a,
+    Cont: TypeAppCont<'a, Self, X, R>,
+  {
+    cont.on_type_app()
+  }
+}
+
+impl<F: ?Sized, G: ?Sized> TypeAppGenericSized for ComposeApp<F, G>
+{
+  fn with_sized_type_app<'a, X: 'a, R: 'a, Cont: 'a>(cont: Cont) -> R
+  where
+    Self: 'a,
+    Cont: TypeAppContSized<'a, Self, X, R>,
+  {
+    cont.on_sized_type_app()
+  }
+}
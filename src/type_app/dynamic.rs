@@ -137,3 +137,123 @@ impl<'a, F: 'a + ?Sized, X: 'a + ?Sized> HasTypeApp<'a, F, X> for App<'a, F, X>
     self.0.get_applied_borrow_mut()
   }
 }
+
+/// A thread safe variant of [App] that can be sent to another
+/// thread, constructed with [wrap_app_send](super::wrap_app_send).
+///
+/// `App<'a, F, X>` is never [Send], as the trait object it wraps
+/// does not know whether the applied type `FX` is `Send`.
+/// `SendApp` requires `FX: Send` at construction time instead,
+/// and can be converted back into a regular [App] with
+/// [into_app](SendApp::into_app) once it has crossed the thread
+/// boundary.
+pub struct SendApp<'a, F: 'a + ?Sized, X: 'a + ?Sized>(
+  pub Box<dyn HasTypeApp<'a, F, X> + Send>,
+);
+
+/// A variant of [App] that is both [Send] and [Sync], constructed
+/// with [wrap_app_sync](super::wrap_app_sync). A `&SyncApp` can be
+/// shared between threads, such as with [std::thread::scope].
+pub struct SyncApp<'a, F: 'a + ?Sized, X: 'a + ?Sized>(
+  pub Box<dyn HasTypeApp<'a, F, X> + Send + Sync>,
+);
+
+impl<'a, F: 'a + ?Sized, X: 'a + ?Sized> SendApp<'a, F, X>
+{
+  pub fn get_applied(self) -> F::Applied
+  where
+    F: TypeApp<'a, X>,
+    F::Applied: Sized,
+  {
+    *self.0.get_applied_box()
+  }
+
+  /// Forget that the applied type is [Send].
+  pub fn into_app(self) -> App<'a, F, X>
+  {
+    App(self.0)
+  }
+}
+
+impl<'a, F: 'a + ?Sized, X: 'a + ?Sized> SyncApp<'a, F, X>
+{
+  pub fn get_applied(self) -> F::Applied
+  where
+    F: TypeApp<'a, X>,
+    F::Applied: Sized,
+  {
+    *self.0.get_applied_box()
+  }
+
+  /// Forget that the applied type is [Sync].
+  pub fn into_send(self) -> SendApp<'a, F, X>
+  {
+    SendApp(self.0)
+  }
+
+  /// Forget that the applied type is [Send] and [Sync].
+  pub fn into_app(self) -> App<'a, F, X>
+  {
+    App(self.0)
+  }
+}
+
+impl<'a, F: 'a + ?Sized, X: 'a + ?Sized> From<SendApp<'a, F, X>>
+  for App<'a, F, X>
+{
+  fn from(fx: SendApp<'a, F, X>) -> Self
+  {
+    fx.into_app()
+  }
+}
+
+impl<'a, F: 'a + ?Sized, X: 'a + ?Sized> From<SyncApp<'a, F, X>>
+  for App<'a, F, X>
+{
+  fn from(fx: SyncApp<'a, F, X>) -> Self
+  {
+    fx.into_app()
+  }
+}
+
+impl<'a, F: 'a + ?Sized, X: 'a + ?Sized> From<SyncApp<'a, F, X>>
+  for SendApp<'a, F, X>
+{
+  fn from(fx: SyncApp<'a, F, X>) -> Self
+  {
+    fx.into_send()
+  }
+}
+
+macro_rules! impl_has_type_app {
+  ( $( $app:ident ),* ) => {
+    $(
+      impl<'a, F: 'a + ?Sized, X: 'a + ?Sized> HasTypeApp<'a, F, X>
+        for $app<'a, F, X>
+      {
+        fn get_applied_box(self: Box<Self>) -> Box<F::Applied>
+        where
+          F: TypeApp<'a, X>,
+        {
+          self.0.get_applied_box()
+        }
+
+        fn get_applied_borrow(&self) -> &F::Applied
+        where
+          F: TypeApp<'a, X>,
+        {
+          self.0.get_applied_borrow()
+        }
+
+        fn get_applied_borrow_mut(&mut self) -> &mut F::Applied
+        where
+          F: TypeApp<'a, X>,
+        {
+          self.0.get_applied_borrow_mut()
+        }
+      }
+    )*
+  };
+}
+
+impl_has_type_app!(SendApp, SyncApp);
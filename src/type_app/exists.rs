@@ -0,0 +1,334 @@
+//! Existential types, `exists X. App<'a, F, X>`.
+//!
+//! An existential value hides the type argument `X` of an `App<'a, F, X>`,
+//! so that values with different `X` can be stored together, e.g. in a
+//! heterogeneous `Vec`. The only way to use an existential value is to
+//! eliminate it with a rank-2 continuation that works for all `X`,
+//! given by [ExistsCont].
+//!
+//! The continuation cannot be chosen freely at the elimination site
+//! in Rust. Calling the polymorphic method `on_exists::<X>` requires
+//! both the continuation type and the hidden `X` to be known at the
+//! same place, while `X` is only known when the value is packed. So
+//! an existential is additionally parameterized by the type `K` of
+//! the continuation that will eliminate it. The packed value is
+//! stored behind an object safe trait that erases `X`, and the method
+//! is instantiated for the hidden `X` when the value is packed. Only
+//! the type of the continuation is fixed this way, while the
+//! continuation value itself is given when eliminating.
+//!
+//! [ExistsWith] additionally packs a [Dict] of the constraint `C` on the
+//! hidden `X`, so that the continuation can make use of it.
+
+use core::marker::PhantomData;
+
+use super::{
+  base::TypeCon,
+  dynamic::App,
+};
+use crate::{
+  constraint::*,
+  poly_fn::PolyFn,
+};
+
+/// A rank-2 continuation `forall X. App<'a, F, X> -> Output`.
+pub trait ExistsCont<'a, F: 'a + ?Sized>
+{
+  type Output;
+
+  fn on_exists<X: 'a>(
+    self,
+    fx: App<'a, F, X>,
+  ) -> Self::Output;
+}
+
+/// A rank-2 continuation `forall X. C X => App<'a, F, X> -> Output`,
+/// with the constraint `C` on `X` given as a [Dict].
+pub trait ExistsWithCont<'a, F: 'a + ?Sized, C>
+{
+  type Output;
+
+  fn on_exists_with<X: 'a>(
+    self,
+    fx: App<'a, F, X>,
+    dict: Dict<C, X>,
+  ) -> Self::Output;
+}
+
+/// `exists X. App<'a, F, X>`, to be eliminated by a continuation of
+/// type `K`.
+pub struct Exists<'a, F: 'a + ?Sized, K>
+where
+  K: ExistsCont<'a, F>,
+{
+  hidden: Box<dyn HasExists<'a, F, K> + 'a>,
+}
+
+/// `exists X. (C X, App<'a, F, X>)`, to be eliminated by a
+/// continuation of type `K`.
+pub struct ExistsWith<'a, F: 'a + ?Sized, C, K>
+where
+  K: ExistsWithCont<'a, F, C>,
+{
+  hidden: Box<dyn HasExistsWith<'a, F, C, K> + 'a>,
+}
+
+/// A packed value whose type argument is erased behind this trait.
+/// The trait is object safe, with the polymorphic method
+/// [ExistsCont::on_exists] instantiated for the hidden `X` by the
+/// implementation, when the value is packed.
+trait HasExists<'a, F: 'a + ?Sized, K>
+where
+  K: ExistsCont<'a, F>,
+{
+  fn elim(
+    self: Box<Self>,
+    cont: K,
+  ) -> K::Output;
+}
+
+/// [HasExists] for [ExistsWith], which also holds the [Dict].
+trait HasExistsWith<'a, F: 'a + ?Sized, C, K>
+where
+  K: ExistsWithCont<'a, F, C>,
+{
+  fn elim(
+    self: Box<Self>,
+    cont: K,
+  ) -> K::Output;
+}
+
+struct Hidden<'a, F: 'a + ?Sized, X: 'a>(App<'a, F, X>);
+
+struct HiddenWith<'a, F: 'a + ?Sized, C, X: 'a>(App<'a, F, X>, Dict<C, X>);
+
+impl<'a, F: 'a + ?Sized, X: 'a, K> HasExists<'a, F, K> for Hidden<'a, F, X>
+where
+  K: ExistsCont<'a, F>,
+{
+  fn elim(
+    self: Box<Self>,
+    cont: K,
+  ) -> K::Output
+  {
+    cont.on_exists(self.0)
+  }
+}
+
+impl<'a, F: 'a + ?Sized, C, X: 'a, K> HasExistsWith<'a, F, C, K>
+  for HiddenWith<'a, F, C, X>
+where
+  K: ExistsWithCont<'a, F, C>,
+{
+  fn elim(
+    self: Box<Self>,
+    cont: K,
+  ) -> K::Output
+  {
+    let HiddenWith(fx, dict) = *self;
+    cont.on_exists_with(fx, dict)
+  }
+}
+
+impl<'a, F: 'a + ?Sized, K: 'a> Exists<'a, F, K>
+where
+  K: ExistsCont<'a, F>,
+{
+  /// Hide the type argument `X` of `fx`.
+  pub fn new<X: 'a>(fx: App<'a, F, X>) -> Self
+  {
+    Exists {
+      hidden: Box::new(Hidden(fx)),
+    }
+  }
+
+  pub fn elim(
+    self,
+    cont: K,
+  ) -> K::Output
+  {
+    self.hidden.elim(cont)
+  }
+}
+
+impl<'a, F: 'a + ?Sized, C: 'a, K: 'a> ExistsWith<'a, F, C, K>
+where
+  K: ExistsWithCont<'a, F, C>,
+{
+  /// Hide the type argument `X` of `fx`, together with a proof of
+  /// the constraint `C` on `X`.
+  pub fn new<X: 'a>(fx: App<'a, F, X>) -> Self
+  where
+    C: HasConstraint<X>,
+  {
+    ExistsWith::with_dict(fx, Dict::new())
+  }
+
+  pub fn with_dict<X: 'a>(
+    fx: App<'a, F, X>,
+    dict: Dict<C, X>,
+  ) -> Self
+  {
+    ExistsWith {
+      hidden: Box::new(HiddenWith(fx, dict)),
+    }
+  }
+
+  pub fn elim(
+    self,
+    cont: K,
+  ) -> K::Output
+  {
+    self.hidden.elim(cont)
+  }
+}
+
+/// A continuation on `F` that applies the polymorphic function
+/// `Trans: F ~> G` to the hidden value, before passing it to the
+/// continuation `K` on `G`.
+pub struct LiftCont<'b, Trans, G, K>
+{
+  trans: &'b Trans,
+  cont: K,
+  target: PhantomData<G>,
+}
+
+impl<'b, Trans, G, K> LiftCont<'b, Trans, G, K>
+{
+  pub fn new(
+    trans: &'b Trans,
+    cont: K,
+  ) -> Self
+  {
+    LiftCont {
+      trans,
+      cont,
+      target: PhantomData,
+    }
+  }
+}
+
+impl<'a, 'b, Trans, F: 'a, G: 'a, K> ExistsCont<'a, F>
+  for LiftCont<'b, Trans, G, K>
+where
+  F: TypeCon,
+  G: TypeCon,
+  Trans: PolyFn<F, G>,
+  K: ExistsCont<'a, G>,
+{
+  type Output = K::Output;
+
+  fn on_exists<X: 'a>(
+    self,
+    fx: App<'a, F, X>,
+  ) -> K::Output
+  {
+    self.cont.on_exists(self.trans.call(fx))
+  }
+}
+
+impl<'a, 'b, Trans, F: 'a, G: 'a, C, K> ExistsWithCont<'a, F, C>
+  for LiftCont<'b, Trans, G, K>
+where
+  F: TypeCon,
+  G: TypeCon,
+  Trans: PolyFn<F, G>,
+  K: ExistsWithCont<'a, G, C>,
+{
+  type Output = K::Output;
+
+  fn on_exists_with<X: 'a>(
+    self,
+    fx: App<'a, F, X>,
+    dict: Dict<C, X>,
+  ) -> K::Output
+  {
+    self.cont.on_exists_with(self.trans.call(fx), dict)
+  }
+}
+
+/// An existential on `F` with the polymorphic function `Trans` to be
+/// applied to it when eliminated, created by [Exists::lift] and
+/// [ExistsWith::lift].
+struct Lifted<'a, Ex, Trans>
+{
+  inner: Ex,
+  trans: &'a Trans,
+}
+
+impl<'a, F: 'a, Trans: 'a, G: 'a, K: 'a> HasExists<'a, G, K>
+  for Lifted<'a, Exists<'a, F, LiftCont<'a, Trans, G, K>>, Trans>
+where
+  F: TypeCon,
+  G: TypeCon,
+  Trans: PolyFn<F, G>,
+  K: ExistsCont<'a, G>,
+{
+  fn elim(
+    self: Box<Self>,
+    cont: K,
+  ) -> K::Output
+  {
+    self.inner.elim(LiftCont::new(self.trans, cont))
+  }
+}
+
+impl<'a, F: 'a, C: 'a, Trans: 'a, G: 'a, K: 'a> HasExistsWith<'a, G, C, K>
+  for Lifted<'a, ExistsWith<'a, F, C, LiftCont<'a, Trans, G, K>>, Trans>
+where
+  F: TypeCon,
+  G: TypeCon,
+  Trans: PolyFn<F, G>,
+  K: ExistsWithCont<'a, G, C>,
+{
+  fn elim(
+    self: Box<Self>,
+    cont: K,
+  ) -> K::Output
+  {
+    self.inner.elim(LiftCont::new(self.trans, cont))
+  }
+}
+
+impl<'a, F: 'a, Trans: 'a, G: 'a, K: 'a>
+  Exists<'a, F, LiftCont<'a, Trans, G, K>>
+where
+  F: TypeCon,
+  G: TypeCon,
+  Trans: PolyFn<F, G>,
+  K: ExistsCont<'a, G>,
+{
+  /// Apply the polymorphic function `Trans: F ~> G` inside the
+  /// existential, with the resulting existential being eliminated
+  /// by `K` directly.
+  pub fn lift(
+    self,
+    trans: &'a Trans,
+  ) -> Exists<'a, G, K>
+  {
+    Exists {
+      hidden: Box::new(Lifted { inner: self, trans }),
+    }
+  }
+}
+
+impl<'a, F: 'a, C: 'a, Trans: 'a, G: 'a, K: 'a>
+  ExistsWith<'a, F, C, LiftCont<'a, Trans, G, K>>
+where
+  F: TypeCon,
+  G: TypeCon,
+  Trans: PolyFn<F, G>,
+  K: ExistsWithCont<'a, G, C>,
+{
+  /// Apply the polymorphic function `Trans: F ~> G` inside the
+  /// existential, keeping the constraint on the hidden type.
+  pub fn lift(
+    self,
+    trans: &'a Trans,
+  ) -> ExistsWith<'a, G, C, K>
+  {
+    ExistsWith {
+      hidden: Box::new(Lifted { inner: self, trans }),
+    }
+  }
+}
@@ -139,3 +139,24 @@ pub trait TypeAppCont<'a, F: ?Sized, X: 'a + ?Sized, R>
   where
     F: TypeApp<'a, X>;
 }
+
+/// A [TypeAppGeneric] type constructor whose applied types are always
+/// sized, such as [VecF](super::VecF). The continuation additionally
+/// learns that the applied type is sized, so that it can be used as
+/// the type argument of another [TypeAppGeneric] type constructor, as
+/// is done by [Compose](super::Compose).
+pub trait TypeAppGenericSized: TypeAppGeneric
+{
+  fn with_sized_type_app<'a, X: 'a, R: 'a, Cont: 'a>(cont: Cont) -> R
+  where
+    Self: 'a,
+    Cont: TypeAppContSized<'a, Self, X, R>;
+}
+
+pub trait TypeAppContSized<'a, F: ?Sized, X: 'a + ?Sized, R>
+{
+  fn on_sized_type_app(self) -> R
+  where
+    F: TypeApp<'a, X>,
+    <F as TypeApp<'a, X>>::Applied: Sized;
+}
@@ -5,7 +5,9 @@ use super::{
   },
   generic::{
     TypeAppCont,
+    TypeAppContSized,
     TypeAppGeneric,
+    TypeAppGenericSized,
   },
 };
 
@@ -34,3 +36,14 @@ impl TypeAppGeneric for Identity
     cont.on_type_app()
   }
 }
+
+impl TypeAppGenericSized for Identity
+{
+  fn with_sized_type_app<'a, X: 'a, R: 'a, Cont: 'a>(cont: Cont) -> R
+  where
+    Self: 'a,
+    Cont: TypeAppContSized<'a, Self, X, R>,
+  {
+    cont.on_sized_type_app()
+  }
+}
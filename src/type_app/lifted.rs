@@ -0,0 +1,419 @@
+//! Standard traits lifted to type constructors.
+//!
+//! Since [App] hides the applied type behind a trait object, it cannot
+//! derive traits such as [PartialEq] or [Clone] from the applied type.
+//! Instead, a type constructor `F` implements a _lifted_ version of
+//! the trait, such as [PartialEq1], which compares `App<F, X>` for
+//! any `X` that implements [PartialEq]. `App<F, X>` then implements the
+//! standard trait whenever both `F` has the lifted instance and `X`
+//! has the element instance.
+//!
+//! ```
+//! # use lambek::type_app::*;
+//! let xs: App<VecF, u32> = wrap_app(vec![1, 2, 3]);
+//! let ys = xs.clone();
+//!
+//! assert_eq!(xs, ys);
+//! assert_eq!(format!("{:?}", ys), "[1, 2, 3]");
+//! ```
+//!
+//! [Compose] is not given lifted instances, as its applied type is
+//! only known to exist for specific type arguments. Use [ComposeApp]
+//! instead, which composes the instances of its two components.
+
+use core::{
+  cmp::Ordering,
+  fmt,
+  hash::{
+    Hash,
+    Hasher,
+  },
+};
+
+use super::*;
+use crate::reference::*;
+
+/// `PartialEq` lifted to a type constructor.
+pub trait PartialEq1: TypeCon
+{
+  fn eq1<'a, X: 'a + PartialEq>(
+    fx: &App<'a, Self, X>,
+    fy: &App<'a, Self, X>,
+  ) -> bool;
+}
+
+/// `Eq` lifted to a type constructor, asserting that [PartialEq1]
+/// is an equivalence relation whenever the element type is [Eq].
+///
+/// Any other type the applied type contains must be [Eq] as well,
+/// so `App<ResultF<f64>, u32>` is [PartialEq] but not [Eq]:
+///
+/// ```compile_fail
+/// # use lambek::type_app::*;
+/// fn assert_eq<T: Eq>() {}
+/// assert_eq::<App<ResultF<f64>, u32>>();
+/// ```
+pub trait Eq1: PartialEq1 {}
+
+/// `PartialOrd` lifted to a type constructor.
+pub trait PartialOrd1: PartialEq1
+{
+  fn partial_cmp1<'a, X: 'a + PartialOrd>(
+    fx: &App<'a, Self, X>,
+    fy: &App<'a, Self, X>,
+  ) -> Option<Ordering>;
+}
+
+/// `Ord` lifted to a type constructor.
+pub trait Ord1: Eq1 + PartialOrd1
+{
+  fn cmp1<'a, X: 'a + Ord>(
+    fx: &App<'a, Self, X>,
+    fy: &App<'a, Self, X>,
+  ) -> Ordering;
+}
+
+/// `Debug` lifted to a type constructor.
+pub trait Debug1: TypeCon
+{
+  fn fmt1<'a, X: 'a + fmt::Debug>(
+    fx: &App<'a, Self, X>,
+    f: &mut fmt::Formatter<'_>,
+  ) -> fmt::Result;
+}
+
+/// `Clone` lifted to a type constructor. Unlike [CloneApp], the
+/// element type is required to be [Clone].
+pub trait Clone1: TypeCon
+{
+  fn clone1<'a, X: 'a + Clone>(fx: &App<'a, Self, X>) -> App<'a, Self, X>;
+}
+
+/// `Hash` lifted to a type constructor.
+pub trait Hash1: TypeCon
+{
+  fn hash1<'a, X: 'a + Hash, H: Hasher>(
+    fx: &App<'a, Self, X>,
+    state: &mut H,
+  );
+}
+
+impl<'a, F: 'a, X: 'a> PartialEq for App<'a, F, X>
+where
+  F: PartialEq1,
+  X: PartialEq,
+{
+  fn eq(
+    &self,
+    other: &Self,
+  ) -> bool
+  {
+    F::eq1(self, other)
+  }
+}
+
+impl<'a, F: 'a, X: 'a> Eq for App<'a, F, X>
+where
+  F: Eq1,
+  X: Eq,
+{
+}
+
+impl<'a, F: 'a, X: 'a> PartialOrd for App<'a, F, X>
+where
+  F: PartialOrd1,
+  X: PartialOrd,
+{
+  fn partial_cmp(
+    &self,
+    other: &Self,
+  ) -> Option<Ordering>
+  {
+    F::partial_cmp1(self, other)
+  }
+}
+
+impl<'a, F: 'a, X: 'a> Ord for App<'a, F, X>
+where
+  F: Ord1,
+  X: Ord,
+{
+  fn cmp(
+    &self,
+    other: &Self,
+  ) -> Ordering
+  {
+    F::cmp1(self, other)
+  }
+}
+
+impl<'a, F: 'a, X: 'a> fmt::Debug for App<'a, F, X>
+where
+  F: Debug1,
+  X: fmt::Debug,
+{
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter<'_>,
+  ) -> fmt::Result
+  {
+    F::fmt1(self, f)
+  }
+}
+
+impl<'a, F: 'a, X: 'a> Clone for App<'a, F, X>
+where
+  F: Clone1,
+  X: Clone,
+{
+  fn clone(&self) -> Self
+  {
+    F::clone1(self)
+  }
+}
+
+impl<'a, F: 'a, X: 'a> Hash for App<'a, F, X>
+where
+  F: Hash1,
+  X: Hash,
+{
+  fn hash<H: Hasher>(
+    &self,
+    state: &mut H,
+  )
+  {
+    F::hash1(self, state)
+  }
+}
+
+// Each proxy is listed as `[generics] proxy [bounds]`, with its applied
+// type implementing the standard trait whenever the element does.
+
+macro_rules! impl_partial_eq1 {
+  ( $( [ $( $gen:tt )* ] $proxy:ty [ $( $bound:tt )* ] ),* $(,)? ) => {
+    $(
+      impl< $( $gen )* > PartialEq1 for $proxy
+      where
+        $( $bound )*
+      {
+        fn eq1<'a, X: 'a + PartialEq>(
+          fx: &App<'a, Self, X>,
+          fy: &App<'a, Self, X>,
+        ) -> bool
+        {
+          fx.get_applied_borrow() == fy.get_applied_borrow()
+        }
+      }
+    )*
+  };
+}
+
+macro_rules! impl_eq1 {
+  ( $( [ $( $gen:tt )* ] $proxy:ty [ $( $bound:tt )* ] ),* $(,)? ) => {
+    $(
+      impl< $( $gen )* > Eq1 for $proxy
+      where
+        $( $bound )*
+      {
+      }
+    )*
+  };
+}
+
+macro_rules! impl_partial_ord1 {
+  ( $( [ $( $gen:tt )* ] $proxy:ty [ $( $bound:tt )* ] ),* $(,)? ) => {
+    $(
+      impl< $( $gen )* > PartialOrd1 for $proxy
+      where
+        $( $bound )*
+      {
+        fn partial_cmp1<'a, X: 'a + PartialOrd>(
+          fx: &App<'a, Self, X>,
+          fy: &App<'a, Self, X>,
+        ) -> Option<Ordering>
+        {
+          fx.get_applied_borrow().partial_cmp(fy.get_applied_borrow())
+        }
+      }
+    )*
+  };
+}
+
+macro_rules! impl_ord1 {
+  ( $( [ $( $gen:tt )* ] $proxy:ty [ $( $bound:tt )* ] ),* $(,)? ) => {
+    $(
+      impl< $( $gen )* > Ord1 for $proxy
+      where
+        $( $bound )*
+      {
+        fn cmp1<'a, X: 'a + Ord>(
+          fx: &App<'a, Self, X>,
+          fy: &App<'a, Self, X>,
+        ) -> Ordering
+        {
+          fx.get_applied_borrow().cmp(fy.get_applied_borrow())
+        }
+      }
+    )*
+  };
+}
+
+macro_rules! impl_debug1 {
+  ( $( [ $( $gen:tt )* ] $proxy:ty [ $( $bound:tt )* ] ),* $(,)? ) => {
+    $(
+      impl< $( $gen )* > Debug1 for $proxy
+      where
+        $( $bound )*
+      {
+        fn fmt1<'a, X: 'a + fmt::Debug>(
+          fx: &App<'a, Self, X>,
+          f: &mut fmt::Formatter<'_>,
+        ) -> fmt::Result
+        {
+          fmt::Debug::fmt(fx.get_applied_borrow(), f)
+        }
+      }
+    )*
+  };
+}
+
+macro_rules! impl_clone1 {
+  ( $( [ $( $gen:tt )* ] $proxy:ty [ $( $bound:tt )* ] ),* $(,)? ) => {
+    $(
+      impl< $( $gen )* > Clone1 for $proxy
+      where
+        $( $bound )*
+      {
+        fn clone1<'a, X: 'a + Clone>(
+          fx: &App<'a, Self, X>
+        ) -> App<'a, Self, X>
+        {
+          wrap_app(fx.get_applied_borrow().clone())
+        }
+      }
+    )*
+  };
+}
+
+macro_rules! impl_hash1 {
+  ( $( [ $( $gen:tt )* ] $proxy:ty [ $( $bound:tt )* ] ),* $(,)? ) => {
+    $(
+      impl< $( $gen )* > Hash1 for $proxy
+      where
+        $( $bound )*
+      {
+        fn hash1<'a, X: 'a + Hash, H: Hasher>(
+          fx: &App<'a, Self, X>,
+          state: &mut H,
+        )
+        {
+          fx.get_applied_borrow().hash(state)
+        }
+      }
+    )*
+  };
+}
+
+impl_partial_eq1!(
+  [] VecF [],
+  [] OptionF [],
+  [E] ResultF<E> [E: PartialEq],
+  [const N: usize] ArrayF<N> [],
+  [] Identity [],
+  [A] Const<A> [A: PartialEq],
+  [F] AppF<F> [F: PartialEq1],
+  [F, G] ComposeApp<F, G> [F: PartialEq1, G: PartialEq1],
+  [] Borrow [],
+  [] BorrowMut [],
+  [] BoxF [],
+  [] Own [],
+);
+
+impl_eq1!(
+  [] VecF [],
+  [] OptionF [],
+  [E] ResultF<E> [E: Eq],
+  [const N: usize] ArrayF<N> [],
+  [] Identity [],
+  [A] Const<A> [A: Eq],
+  [F] AppF<F> [F: Eq1],
+  [F, G] ComposeApp<F, G> [F: Eq1, G: Eq1],
+  [] Borrow [],
+  [] BorrowMut [],
+  [] BoxF [],
+  [] Own [],
+);
+
+impl_partial_ord1!(
+  [] VecF [],
+  [] OptionF [],
+  [E] ResultF<E> [E: PartialOrd],
+  [const N: usize] ArrayF<N> [],
+  [] Identity [],
+  [A] Const<A> [A: PartialOrd],
+  [F] AppF<F> [F: PartialOrd1],
+  [F, G] ComposeApp<F, G> [F: PartialOrd1, G: PartialOrd1],
+  [] Borrow [],
+  [] BorrowMut [],
+  [] BoxF [],
+  [] Own [],
+);
+
+impl_ord1!(
+  [] VecF [],
+  [] OptionF [],
+  [E] ResultF<E> [E: Ord],
+  [const N: usize] ArrayF<N> [],
+  [] Identity [],
+  [A] Const<A> [A: Ord],
+  [F] AppF<F> [F: Ord1],
+  [F, G] ComposeApp<F, G> [F: Ord1, G: Ord1],
+  [] Borrow [],
+  [] BorrowMut [],
+  [] BoxF [],
+  [] Own [],
+);
+
+impl_debug1!(
+  [] VecF [],
+  [] OptionF [],
+  [E] ResultF<E> [E: fmt::Debug],
+  [const N: usize] ArrayF<N> [],
+  [] Identity [],
+  [A] Const<A> [A: fmt::Debug],
+  [F] AppF<F> [F: Debug1],
+  [F, G] ComposeApp<F, G> [F: Debug1, G: Debug1],
+  [] Borrow [],
+  [] BorrowMut [],
+  [] BoxF [],
+  [] Own [],
+);
+
+impl_clone1!(
+  [] VecF [],
+  [] OptionF [],
+  [E] ResultF<E> [E: Clone],
+  [const N: usize] ArrayF<N> [],
+  [] Identity [],
+  [A] Const<A> [A: Clone],
+  [F] AppF<F> [F: Clone1],
+  [F, G] ComposeApp<F, G> [F: Clone1, G: Clone1],
+  [] Borrow [],
+  [] BoxF [],
+  [] Own [],
+);
+
+impl_hash1!(
+  [] VecF [],
+  [] OptionF [],
+  [E] ResultF<E> [E: Hash],
+  [const N: usize] ArrayF<N> [],
+  [] Identity [],
+  [A] Const<A> [A: Hash],
+  [F] AppF<F> [F: Hash1],
+  [F, G] ComposeApp<F, G> [F: Hash1, G: Hash1],
+  [] Borrow [],
+  [] BorrowMut [],
+  [] BoxF [],
+  [] Own [],
+);
@@ -29,16 +29,23 @@
 pub mod base;
 pub mod compose;
 pub mod dynamic;
+pub mod exists;
 pub mod generic;
 pub mod identity;
+pub mod lifted;
 
-use core::marker::PhantomData;
+use core::{
+  any::Any,
+  marker::PhantomData,
+};
 
 pub use base::*;
 pub use compose::*;
 pub use dynamic::*;
+pub use exists::*;
 pub use generic::*;
 pub use identity::*;
+pub use lifted::*;
 
 impl<F> TypeAppGeneric for F
 where
@@ -58,6 +65,31 @@ pub trait CloneApp: TypeCon
   fn clone_app<'a, X: 'a>(fx: &App<'a, Self, X>) -> App<'a, Self, X>;
 }
 
+/// The applied type `FX` behind the trait objects created by
+/// [wrap_app] and its thread safe variants. `Wrapped<FX>` is
+/// [Send] or [Sync] exactly when `FX` is.
+struct Wrapped<FX>(FX);
+
+impl<'a, F: 'a, X: 'a, FX: 'a> HasTypeApp<'a, F, X> for Wrapped<FX>
+where
+  F: TypeApp<'a, X, Applied = FX>,
+{
+  fn get_applied_box(self: Box<Self>) -> Box<FX>
+  {
+    Box::new(self.0)
+  }
+
+  fn get_applied_borrow(&self) -> &FX
+  {
+    &self.0
+  }
+
+  fn get_applied_borrow_mut(&mut self) -> &mut FX
+  {
+    &mut self.0
+  }
+}
+
 /// Wraps a type `FX` into [App] in the presence of the [TypeApp]
 /// constraint, allowing subsequent use of [App] to not depend
 /// on [TypeApp].
@@ -65,29 +97,40 @@ pub fn wrap_app<'a, F: 'a, X: 'a, FX: 'a>(fx: FX) -> App<'a, F, X>
 where
   F: TypeApp<'a, X, Applied = FX>,
 {
-  struct Applied<X>(X);
-
-  impl<'a, F: 'a, X: 'a, FX: 'a> HasTypeApp<'a, F, X> for Applied<FX>
-  where
-    F: TypeApp<'a, X, Applied = FX>,
-  {
-    fn get_applied_box(self: Box<Self>) -> Box<FX>
-    {
-      Box::new(self.0)
-    }
+  App(Box::new(Wrapped(fx)))
+}
 
-    fn get_applied_borrow(&self) -> &FX
-    {
-      &self.0
-    }
+/// Wraps a [Send] type `FX` into [SendApp], which can be moved
+/// to another thread.
+pub fn wrap_app_send<'a, F: 'a, X: 'a, FX: 'a>(fx: FX) -> SendApp<'a, F, X>
+where
+  F: TypeApp<'a, X, Applied = FX>,
+  FX: Send,
+{
+  SendApp(Box::new(Wrapped(fx)))
+}
 
-    fn get_applied_borrow_mut(&mut self) -> &mut FX
-    {
-      &mut self.0
-    }
-  }
+/// Wraps a [Send] and [Sync] type `FX` into [SyncApp], which can
+/// be shared between threads.
+pub fn wrap_app_sync<'a, F: 'a, X: 'a, FX: 'a>(fx: FX) -> SyncApp<'a, F, X>
+where
+  F: TypeApp<'a, X, Applied = FX>,
+  FX: Send + Sync,
+{
+  SyncApp(Box::new(Wrapped(fx)))
+}
 
-  App(Box::new(Applied(fx)))
+/// Recover an `App<'a, F, X>` from a type-erased container holding
+/// the applied type `FX`, or give back the container if it holds
+/// a value of some other type.
+pub fn downcast_app<'a, F: 'a, X: 'a>(
+  fx: Box<dyn Any>
+) -> Result<App<'a, F, X>, Box<dyn Any>>
+where
+  F: TypeApp<'a, X>,
+  F::Applied: Sized + 'static,
+{
+  fx.downcast::<F::Applied>().map(|fx| wrap_app(*fx))
 }
 
 #[macro_export]
@@ -126,6 +169,19 @@ macro_rules! impl_type_app {
         cont.on_type_app()
       }
     }
+
+    impl TypeAppGenericSized for $proxy
+    {
+      fn with_sized_type_app<'a, X : 'a, R : 'a, Cont: 'a>(
+        cont : Cont
+      ) -> R
+      where
+        Self : 'a,
+        Cont: TypeAppContSized<'a, Self, X, R>,
+      {
+        cont.on_sized_type_app()
+      }
+    }
   };
   ( $proxy:ident < $( $types:ident ),+ $(,)? >, $target:ident ) => {
     impl < $( $types ),* >
@@ -141,7 +197,7 @@ macro_rules! impl_type_app {
       TypeAppGeneric for $proxy < $( $types ),* >
     {
       fn with_type_app<'a, X : 'a, R : 'a, Cont: 'a>(
-        cont : Box < Cont >
+        cont : Cont
       ) -> R
       where
         Self : 'a,
@@ -150,6 +206,20 @@ macro_rules! impl_type_app {
         cont.on_type_app()
       }
     }
+
+    impl < $( $types ),* >
+      TypeAppGenericSized for $proxy < $( $types ),* >
+    {
+      fn with_sized_type_app<'a, X : 'a, R : 'a, Cont: 'a>(
+        cont : Cont
+      ) -> R
+      where
+        Self : 'a,
+        Cont: TypeAppContSized<'a, Self, X, R>,
+      {
+        cont.on_sized_type_app()
+      }
+    }
   }
 }
 
@@ -169,6 +239,28 @@ impl<'a, A: 'a + ?Sized, X: 'a + ?Sized> TypeApp<'a, X> for Const<A>
   type Applied = A;
 }
 
+impl<A: ?Sized> TypeAppGenericUnsized for Const<A>
+{
+  fn with_type_app<'a, X: 'a + ?Sized, R: 'a, Cont: 'a>(cont: Cont) -> R
+  where
+    Self: 'a,
+    Cont: TypeAppCont<'a, Self, X, R>,
+  {
+    cont.on_type_app()
+  }
+}
+
+impl<A> TypeAppGenericSized for Const<A>
+{
+  fn with_sized_type_app<'a, X: 'a, R: 'a, Cont: 'a>(cont: Cont) -> R
+  where
+    Self: 'a,
+    Cont: TypeAppContSized<'a, Self, X, R>,
+  {
+    cont.on_sized_type_app()
+  }
+}
+
 pub struct AppF<F: ?Sized>(PhantomData<F>);
 
 impl<F: ?Sized> TypeCon for AppF<F> {}
@@ -178,6 +270,28 @@ impl<'a, X: 'a + ?Sized, F: 'a + ?Sized> TypeApp<'a, X> for AppF<F>
   type Applied = App<'a, F, X>;
 }
 
+impl<F: ?Sized> TypeAppGenericUnsized for AppF<F>
+{
+  fn with_type_app<'a, X: 'a + ?Sized, R: 'a, Cont: 'a>(cont: Cont) -> R
+  where
+    Self: 'a,
+    Cont: TypeAppCont<'a, Self, X, R>,
+  {
+    cont.on_type_app()
+  }
+}
+
+impl<F: ?Sized> TypeAppGenericSized for AppF<F>
+{
+  fn with_sized_type_app<'a, X: 'a, R: 'a, Cont: 'a>(cont: Cont) -> R
+  where
+    Self: 'a,
+    Cont: TypeAppContSized<'a, Self, X, R>,
+  {
+    cont.on_sized_type_app()
+  }
+}
+
 /// `App<VecF, X> ~ Vec<X>`
 pub enum VecF {}
 impl_type_app!(VecF, Vec);
@@ -195,3 +309,57 @@ impl<'a, E: 'a, X: 'a> TypeApp<'a, X> for ResultF<E>
 {
   type Applied = Result<X, E>;
 }
+
+impl<E> TypeAppGeneric for ResultF<E>
+{
+  fn with_type_app<'a, X: 'a, R: 'a, Cont: 'a>(cont: Cont) -> R
+  where
+    Self: 'a,
+    Cont: TypeAppCont<'a, Self, X, R>,
+  {
+    cont.on_type_app()
+  }
+}
+
+impl<E> TypeAppGenericSized for ResultF<E>
+{
+  fn with_sized_type_app<'a, X: 'a, R: 'a, Cont: 'a>(cont: Cont) -> R
+  where
+    Self: 'a,
+    Cont: TypeAppContSized<'a, Self, X, R>,
+  {
+    cont.on_sized_type_app()
+  }
+}
+
+/// `App<ArrayF<N>, X> ~ [X; N]`
+pub struct ArrayF<const N: usize>;
+
+impl<const N: usize> TypeCon for ArrayF<N> {}
+
+impl<'a, X: 'a, const N: usize> TypeApp<'a, X> for ArrayF<N>
+{
+  type Applied = [X; N];
+}
+
+impl<const N: usize> TypeAppGeneric for ArrayF<N>
+{
+  fn with_type_app<'a, X: 'a, R: 'a, Cont: 'a>(cont: Cont) -> R
+  where
+    Self: 'a,
+    Cont: TypeAppCont<'a, Self, X, R>,
+  {
+    cont.on_type_app()
+  }
+}
+
+impl<const N: usize> TypeAppGenericSized for ArrayF<N>
+{
+  fn with_sized_type_app<'a, X: 'a, R: 'a, Cont: 'a>(cont: Cont) -> R
+  where
+    Self: 'a,
+    Cont: TypeAppContSized<'a, Self, X, R>,
+  {
+    cont.on_sized_type_app()
+  }
+}
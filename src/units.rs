@@ -0,0 +1,261 @@
+//! Compile-time units of measure.
+//!
+//! A [`Quantity<T, D>`](Quantity) is a value of type `T`, such as
+//! `f64`, with the physical dimension `D`. The dimension is a product
+//! row of type-level [integer](crate::integer) exponents, one for each
+//! base dimension. The [Dims] alias gives the row for the base
+//! dimensions length, mass and time, e.g. velocity is `Dims<P1, Zero, N1>`
+//! for `length * time^-1`.
+//!
+//! Multiplying two quantities adds their exponents and dividing them
+//! subtracts the exponents, all at the type level:
+//!
+//! ```
+//! # use lambek::units::*;
+//! let distance: Quantity<f64, Length> = Quantity::new(100.0);
+//! let time: Quantity<f64, Time> = Quantity::new(8.0);
+//!
+//! let velocity: Quantity<f64, Velocity> = distance / time;
+//! let acceleration: Quantity<f64, Acceleration> = velocity / time;
+//! let mass: Quantity<f64, Mass> = Quantity::new(2.0);
+//! let force: Quantity<f64, Force> = mass * acceleration;
+//!
+//! assert_eq!(force.into_value(), 3.125);
+//! ```
+//!
+//! Quantities can only be added or subtracted if they have the same
+//! dimension:
+//!
+//! ```compile_fail
+//! # use lambek::units::*;
+//! let distance: Quantity<f64, Length> = Quantity::new(100.0);
+//! let time: Quantity<f64, Time> = Quantity::new(8.0);
+//!
+//! let _ = distance + time;
+//! ```
+
+use core::{
+  cmp::Ordering,
+  fmt,
+  marker::PhantomData,
+  ops,
+};
+
+use crate::{
+  integer::*,
+  nat::*,
+  product::*,
+};
+
+/// A value of type `T` with the dimension `D`.
+pub struct Quantity<T, D>
+{
+  value: T,
+  dims: PhantomData<D>,
+}
+
+pub type Zero = Pos<Z>;
+pub type P1 = Pos<S<Z>>;
+pub type P2 = Pos<S<S<Z>>>;
+pub type P3 = Pos<S<S<S<Z>>>>;
+pub type N1 = Neg<S<Z>>;
+pub type N2 = Neg<S<S<Z>>>;
+pub type N3 = Neg<S<S<S<Z>>>>;
+
+/// The dimension `length^L * mass^M * time^T`.
+pub type Dims<L, M, T> = Cons<L, Cons<M, Cons<T, Top>>>;
+
+pub type Dimensionless = Dims<Zero, Zero, Zero>;
+pub type Length = Dims<P1, Zero, Zero>;
+pub type Mass = Dims<Zero, P1, Zero>;
+pub type Time = Dims<Zero, Zero, P1>;
+pub type Area = Dims<P2, Zero, Zero>;
+pub type Volume = Dims<P3, Zero, Zero>;
+pub type Frequency = Dims<Zero, Zero, N1>;
+pub type Velocity = Dims<P1, Zero, N1>;
+pub type Acceleration = Dims<P1, Zero, N2>;
+pub type Force = Dims<P1, P1, N2>;
+pub type Energy = Dims<P2, P1, N2>;
+
+/// Type-level multiplication of dimensions, which adds the exponents
+/// of `Self` and `D` pairwise.
+pub trait MulDims<D>
+{
+  type Output;
+}
+
+/// Type-level division of dimensions, which subtracts the exponents
+/// of `D` from `Self` pairwise.
+pub trait DivDims<D>
+{
+  type Output;
+}
+
+pub type Multiplied<D1, D2> = <D1 as MulDims<D2>>::Output;
+pub type Divided<D1, D2> = <D1 as DivDims<D2>>::Output;
+
+impl MulDims<Top> for Top
+{
+  type Output = Top;
+}
+
+impl<E1, E2, Tail1, Tail2> MulDims<Cons<E2, Tail2>> for Cons<E1, Tail1>
+where
+  E1: AddInt<E2>,
+  E2: Integer,
+  Tail1: MulDims<Tail2>,
+{
+  type Output = Cons<IntPlus<E1, E2>, Multiplied<Tail1, Tail2>>;
+}
+
+impl DivDims<Top> for Top
+{
+  type Output = Top;
+}
+
+impl<E1, E2, Tail1, Tail2> DivDims<Cons<E2, Tail2>> for Cons<E1, Tail1>
+where
+  E1: AddInt<Negated<E2>>,
+  E2: Negate,
+  Tail1: DivDims<Tail2>,
+{
+  type Output = Cons<IntMinus<E1, E2>, Divided<Tail1, Tail2>>;
+}
+
+impl<T, D> Quantity<T, D>
+{
+  pub fn new(value: T) -> Self
+  {
+    Quantity {
+      value,
+      dims: PhantomData,
+    }
+  }
+
+  pub fn value(&self) -> &T
+  {
+    &self.value
+  }
+
+  pub fn into_value(self) -> T
+  {
+    self.value
+  }
+}
+
+impl<T, U, D> ops::Add<Quantity<U, D>> for Quantity<T, D>
+where
+  T: ops::Add<U>,
+{
+  type Output = Quantity<T::Output, D>;
+
+  fn add(
+    self,
+    other: Quantity<U, D>,
+  ) -> Self::Output
+  {
+    Quantity::new(self.value + other.value)
+  }
+}
+
+impl<T, U, D> ops::Sub<Quantity<U, D>> for Quantity<T, D>
+where
+  T: ops::Sub<U>,
+{
+  type Output = Quantity<T::Output, D>;
+
+  fn sub(
+    self,
+    other: Quantity<U, D>,
+  ) -> Self::Output
+  {
+    Quantity::new(self.value - other.value)
+  }
+}
+
+impl<T, U, D1, D2> ops::Mul<Quantity<U, D2>> for Quantity<T, D1>
+where
+  T: ops::Mul<U>,
+  D1: MulDims<D2>,
+{
+  type Output = Quantity<T::Output, Multiplied<D1, D2>>;
+
+  fn mul(
+    self,
+    other: Quantity<U, D2>,
+  ) -> Self::Output
+  {
+    Quantity::new(self.value * other.value)
+  }
+}
+
+impl<T, U, D1, D2> ops::Div<Quantity<U, D2>> for Quantity<T, D1>
+where
+  T: ops::Div<U>,
+  D1: DivDims<D2>,
+{
+  type Output = Quantity<T::Output, Divided<D1, D2>>;
+
+  fn div(
+    self,
+    other: Quantity<U, D2>,
+  ) -> Self::Output
+  {
+    Quantity::new(self.value / other.value)
+  }
+}
+
+impl<T, D> ops::Neg for Quantity<T, D>
+where
+  T: ops::Neg,
+{
+  type Output = Quantity<T::Output, D>;
+
+  fn neg(self) -> Self::Output
+  {
+    Quantity::new(-self.value)
+  }
+}
+
+impl<T: Clone, D> Clone for Quantity<T, D>
+{
+  fn clone(&self) -> Self
+  {
+    Quantity::new(self.value.clone())
+  }
+}
+
+impl<T: Copy, D> Copy for Quantity<T, D> {}
+
+impl<T: PartialEq, D> PartialEq for Quantity<T, D>
+{
+  fn eq(
+    &self,
+    other: &Self,
+  ) -> bool
+  {
+    self.value == other.value
+  }
+}
+
+impl<T: PartialOrd, D> PartialOrd for Quantity<T, D>
+{
+  fn partial_cmp(
+    &self,
+    other: &Self,
+  ) -> Option<Ordering>
+  {
+    self.value.partial_cmp(&other.value)
+  }
+}
+
+impl<T: fmt::Debug, D> fmt::Debug for Quantity<T, D>
+{
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter<'_>,
+  ) -> fmt::Result
+  {
+    self.value.fmt(f)
+  }
+}
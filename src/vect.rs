@@ -0,0 +1,394 @@
+//! Length-indexed vectors.
+//!
+//! `Vect<N, T>` is a vector of `T` with its length statically known
+//! as the type-level natural number `N`. Operations such as [Vect::head]
+//! are only available on non-empty vectors `Vect<S<N>, T>`, and
+//! [Vect::zip] only accepts vectors of the same length. Indexing is
+//! done with the bounded index [`Fin<N>`](Fin), which can only hold
+//! values less than `N`, so that it never goes out of bound.
+//!
+//! ```
+//! # use lambek::{nat::*, vect::*};
+//! let xs = Vect::new().push(1).push(2);
+//! let ys = Vect::new().push("a").push("b");
+//!
+//! let zs: Vect<S<S<Z>>, (u32, &str)> = xs.zip(ys);
+//!
+//! assert_eq!(*zs.head(), (1, "a"));
+//! assert_eq!(zs[Fin::zero().succ()], (2, "b"));
+//! ```
+//!
+//! Taking the head of an empty vector is rejected at compile time:
+//!
+//! ```compile_fail
+//! # use lambek::{nat::*, vect::*};
+//! let xs: Vect<Z, u32> = Vect::new();
+//! xs.head();
+//! ```
+
+use core::{
+  convert::TryFrom,
+  fmt,
+  marker::PhantomData,
+  ops::{
+    Index,
+    IndexMut,
+  },
+};
+
+use crate::{
+  bi_type_app::*,
+  function::*,
+  functor::*,
+  nat::*,
+  type_app::*,
+};
+
+/// A vector of `T` with exactly `N` elements.
+pub struct Vect<N, T>
+{
+  items: Vec<T>,
+  length: PhantomData<N>,
+}
+
+/// A natural number that is less than `N`.
+pub struct Fin<N>
+{
+  value: usize,
+  bound: PhantomData<N>,
+}
+
+define_type_app!(VectF<N>, Vect);
+
+impl<N, T> Vect<N, T>
+{
+  fn from_vec_unchecked(items: Vec<T>) -> Self
+  {
+    Vect {
+      items,
+      length: PhantomData,
+    }
+  }
+
+  /// Returns `None` if the length of `items` is not `N`.
+  pub fn from_vec(items: Vec<T>) -> Option<Self>
+  where
+    N: Nat,
+  {
+    if items.len() == N::VALUE {
+      Some(Vect::from_vec_unchecked(items))
+    } else {
+      None
+    }
+  }
+
+  /// Convert an array of the literal length `M` into a vector of the
+  /// corresponding length `N`.
+  pub fn from_array<const M: usize>(items: [T; M]) -> Self
+  where
+    Literal<M>: ToNat<Nat = N>,
+  {
+    Vect::from_vec_unchecked(IntoIterator::into_iter(items).collect())
+  }
+
+  /// Convert the vector into an array of the literal length `M`
+  /// corresponding to `N`.
+  pub fn into_array<const M: usize>(self) -> [T; M]
+  where
+    Literal<M>: ToNat<Nat = N>,
+  {
+    match <[T; M]>::try_from(self.items) {
+      Ok(items) => items,
+      Err(_) => unreachable!("the length of Vect<N, T> is always N"),
+    }
+  }
+
+  pub fn len(&self) -> usize
+  where
+    N: Nat,
+  {
+    N::VALUE
+  }
+
+  pub fn is_empty(&self) -> bool
+  where
+    N: Nat,
+  {
+    N::VALUE == 0
+  }
+
+  pub fn as_slice(&self) -> &[T]
+  {
+    &self.items
+  }
+
+  pub fn into_vec(self) -> Vec<T>
+  {
+    self.items
+  }
+
+  pub fn iter(&self) -> core::slice::Iter<'_, T>
+  {
+    self.items.iter()
+  }
+
+  pub fn push(
+    mut self,
+    x: T,
+  ) -> Vect<S<N>, T>
+  {
+    self.items.push(x);
+    Vect::from_vec_unchecked(self.items)
+  }
+
+  pub fn append<M>(
+    mut self,
+    other: Vect<M, T>,
+  ) -> Vect<Plus<N, M>, T>
+  where
+    N: Add<M>,
+    M: Nat,
+  {
+    self.items.extend(other.items);
+    Vect::from_vec_unchecked(self.items)
+  }
+
+  pub fn zip<U>(
+    self,
+    other: Vect<N, U>,
+  ) -> Vect<N, (T, U)>
+  {
+    Vect::from_vec_unchecked(self.items.into_iter().zip(other.items).collect())
+  }
+}
+
+impl<T> Vect<Z, T>
+{
+  pub fn new() -> Self
+  {
+    Vect::from_vec_unchecked(Vec::new())
+  }
+}
+
+impl<T> Default for Vect<Z, T>
+{
+  fn default() -> Self
+  {
+    Vect::new()
+  }
+}
+
+impl<N, T> Vect<S<N>, T>
+{
+  pub fn head(&self) -> &T
+  {
+    &self.items[0]
+  }
+
+  pub fn last(&self) -> &T
+  {
+    &self.items[self.items.len() - 1]
+  }
+
+  pub fn tail(self) -> Vect<N, T>
+  {
+    self.uncons().1
+  }
+
+  /// Split the vector into its first element and the rest.
+  pub fn uncons(mut self) -> (T, Vect<N, T>)
+  {
+    let x = self.items.remove(0);
+    (x, Vect::from_vec_unchecked(self.items))
+  }
+
+  /// Split the vector into the rest and its last element.
+  pub fn pop(mut self) -> (Vect<N, T>, T)
+  {
+    let x = self.items.pop().unwrap();
+    (Vect::from_vec_unchecked(self.items), x)
+  }
+}
+
+impl<N, T> Index<Fin<N>> for Vect<N, T>
+{
+  type Output = T;
+
+  fn index(
+    &self,
+    i: Fin<N>,
+  ) -> &T
+  {
+    &self.items[i.value]
+  }
+}
+
+impl<N, T> IndexMut<Fin<N>> for Vect<N, T>
+{
+  fn index_mut(
+    &mut self,
+    i: Fin<N>,
+  ) -> &mut T
+  {
+    &mut self.items[i.value]
+  }
+}
+
+impl<N, T: Clone> Clone for Vect<N, T>
+{
+  fn clone(&self) -> Self
+  {
+    Vect::from_vec_unchecked(self.items.clone())
+  }
+}
+
+impl<N, T: PartialEq> PartialEq for Vect<N, T>
+{
+  fn eq(
+    &self,
+    other: &Self,
+  ) -> bool
+  {
+    self.items == other.items
+  }
+}
+
+impl<N, T: fmt::Debug> fmt::Debug for Vect<N, T>
+{
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter<'_>,
+  ) -> fmt::Result
+  {
+    self.items.fmt(f)
+  }
+}
+
+impl<N> Fin<S<N>>
+{
+  pub fn zero() -> Self
+  {
+    Fin {
+      value: 0,
+      bound: PhantomData,
+    }
+  }
+}
+
+impl<N> Fin<N>
+{
+  /// Returns `None` if `value` is not less than `N`.
+  pub fn new(value: usize) -> Option<Self>
+  where
+    N: Nat,
+  {
+    if value < N::VALUE {
+      Some(Fin {
+        value,
+        bound: PhantomData,
+      })
+    } else {
+      None
+    }
+  }
+
+  pub fn succ(self) -> Fin<S<N>>
+  {
+    Fin {
+      value: self.value + 1,
+      bound: PhantomData,
+    }
+  }
+
+  /// Relax the bound of the index to `S<N>`.
+  pub fn weaken(self) -> Fin<S<N>>
+  {
+    Fin {
+      value: self.value,
+      bound: PhantomData,
+    }
+  }
+
+  pub fn value(&self) -> usize
+  {
+    self.value
+  }
+}
+
+impl<N> Clone for Fin<N>
+{
+  fn clone(&self) -> Self
+  {
+    *self
+  }
+}
+
+impl<N> Copy for Fin<N> {}
+
+impl<Func, N> Functor<Func> for VectF<N>
+where
+  Func: IsFnMut,
+{
+  fn fmap<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mut mapper: BiApp<'b, Func, A, B>,
+  ) -> App<'a, Self, B>
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    let items = fa
+      .get_applied()
+      .items
+      .into_iter()
+      .map(|x| Func::apply_mut(&mut mapper, x))
+      .collect();
+
+    wrap_app(Vect::from_vec_unchecked(items))
+  }
+}
+
+impl<Func, N> Foldable<Func> for VectF<N>
+where
+  Func: IsFnMut,
+{
+  fn fold_left<'a, 'b, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    init: B,
+    mut folder: BiApp<'b, Func, (B, A), B>,
+  ) -> B
+  where
+    Self: 'a,
+    'a: 'b,
+  {
+    fa.get_applied()
+      .items
+      .into_iter()
+      .fold(init, |b, a| Func::apply_mut(&mut folder, (b, a)))
+  }
+}
+
+impl<Func, N> Traversable<Func> for VectF<N>
+where
+  Func: IsFnMut + WrapFn,
+{
+  fn traverse<'a, 'b, G: 'a, A: 'a, B: 'a>(
+    fa: App<'a, Self, A>,
+    mapper: BiApp<'b, Func, A, App<'a, G, B>>,
+  ) -> App<'a, G, App<'a, Self, B>>
+  where
+    Self: 'a,
+    G: Applicative<Func>,
+    'a: 'b,
+  {
+    let items = wrap_app(fa.get_applied().items);
+    let gbs = VecF::traverse(items, mapper);
+
+    G::fmap(
+      gbs,
+      Func::wrap_fn(|bs: App<'a, VecF, B>| {
+        wrap_app(Vect::from_vec_unchecked(bs.get_applied()))
+      }),
+    )
+  }
+}
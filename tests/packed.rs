@@ -0,0 +1,137 @@
+//! Allocation counts of the packed row representation. These live in
+//! their own test binary, since they replace the global allocator.
+
+use std::{
+  alloc::{
+    GlobalAlloc,
+    Layout,
+    System,
+  },
+  cell::Cell,
+};
+
+use lambek::{
+  packed::*,
+  poly_fn,
+  product::*,
+  row::*,
+  type_app::*,
+};
+
+struct CountingAlloc;
+
+thread_local! {
+  static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc
+{
+  unsafe fn alloc(
+    &self,
+    layout: Layout,
+  ) -> *mut u8
+  {
+    let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+    System.alloc(layout)
+  }
+
+  unsafe fn dealloc(
+    &self,
+    ptr: *mut u8,
+    layout: Layout,
+  )
+  {
+    System.dealloc(ptr, layout)
+  }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Count the heap allocations made by the current thread while
+/// running `body`.
+fn count_allocations<R>(body: impl FnOnce() -> R) -> (R, usize)
+{
+  let before = ALLOCATIONS.with(|count| count.get());
+  let res = body();
+  let after = ALLOCATIONS.with(|count| count.get());
+  (res, after - before)
+}
+
+type Row4 = Cons<u64, Top>;
+type Row3 = Cons<u32, Row4>;
+type Row2 = Cons<u16, Row3>;
+type Row = Cons<u8, Row2>;
+
+fn boxed_row<'a>() -> AppRow<'a, Row, OptionF>
+{
+  let top = wrap_row::<Top, OptionF>(Top);
+  let row4 = wrap_row::<Row4, OptionF>(Cons(wrap_app(Some(4)), top));
+  let row3 = wrap_row::<Row3, OptionF>(Cons(wrap_app(Some(3)), row4));
+  let row2 = wrap_row::<Row2, OptionF>(Cons(wrap_app(Some(2)), row3));
+
+  wrap_row::<Row, OptionF>(Cons(wrap_app(Some(1)), row2))
+}
+
+fn packed_row<'a>() -> AppRow<'a, Packed<Row>, OptionF>
+{
+  wrap_row::<Packed<Row>, OptionF>(Cons(
+    Some(1),
+    Cons(Some(2), Cons(Some(3), Cons(Some(4), Top))),
+  ))
+}
+
+poly_fn! {
+  struct ToResult<X>(fx: App<OptionF, X>) -> App<ResultF<()>, X> {
+    wrap_app(fx.get_applied().ok_or(()))
+  }
+}
+
+#[test]
+fn test_packed_row_allocations()
+{
+  let (boxed, boxed_count) = count_allocations(boxed_row);
+  let (packed, packed_count) = count_allocations(packed_row);
+
+  // One allocation for each field and one for each non-empty tail.
+  assert_eq!(boxed_count, 8);
+  assert_eq!(packed_count, 1);
+
+  let Cons(a, Cons(b, Cons(c, Cons(d, Top)))) = *packed.get_applied();
+  assert_eq!((a, b, c, d), (Some(1), Some(2), Some(3), Some(4)));
+
+  let (repacked, repacked_count) = count_allocations(|| pack_row(boxed));
+  // Unwrapping each field from its App moves it into a new box.
+  assert_eq!(repacked_count, 4 + 1);
+
+  let Cons(a, Cons(b, Cons(c, Cons(d, Top)))) = *repacked.get_applied();
+  assert_eq!((a, b, c, d), (Some(1), Some(2), Some(3), Some(4)));
+}
+
+#[test]
+fn test_lift_packed_allocations()
+{
+  let row = packed_row();
+  let (lifted, lifted_count) = count_allocations(|| {
+    lift_packed::<Row, OptionF, ResultF<()>, _>(&ToResult, row)
+  });
+
+  // Each field is wrapped into and unwrapped from an App on both
+  // sides of the polymorphic function, plus the packed row itself.
+  assert_eq!(lifted_count, 4 * 4 + 1);
+
+  let Cons(a, Cons(b, Cons(c, Cons(d, Top)))) = *lifted.get_applied();
+  assert_eq!((a, b, c, d), (Ok(1), Ok(2), Ok(3), Ok(4)));
+
+  // Going through the boxed representation additionally allocates
+  // every tail, and every field on the way back.
+  let row = packed_row();
+  let (relifted, relifted_count) = count_allocations(|| {
+    pack_row(Row::lift(&ToResult, unpack_row(row)))
+  });
+
+  assert_eq!(relifted_count, 6 * 4 + 1);
+
+  let Cons(a, Cons(b, Cons(c, Cons(d, Top)))) = *relifted.get_applied();
+  assert_eq!((a, b, c, d), (Ok(1), Ok(2), Ok(3), Ok(4)));
+}